@@ -0,0 +1,332 @@
+use cosmwasm_std::{Decimal256, Uint128, Uint256};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    error::{CommonError, CommonResult},
+    signed_decimal::{RoundingMode, SignedDecimal},
+};
+
+fn require_values(values: &[SignedDecimal]) -> CommonResult<()> {
+    if values.is_empty() {
+        return Err(CommonError::Generic(
+            "Cannot aggregate an empty set of values".to_string(),
+        ));
+    }
+    Ok(())
+}
+
+/// Median of `values`, which are sorted in place. With an even count the two
+/// middle values are averaged and the result rounded down, so it doesn't
+/// depend on the input order.
+pub fn median(values: &mut [SignedDecimal]) -> CommonResult<SignedDecimal> {
+    require_values(values)?;
+    values.sort_unstable();
+    let mid = values.len() / 2;
+    if values.len() % 2 == 1 {
+        return Ok(values[mid]);
+    }
+    let (lower, upper) = (values[mid - 1], values[mid]);
+    if lower.is_lt_zero() == upper.is_lt_zero() {
+        // The gap between values of the same sign always fits
+        let gap = upper.checked_sub(lower)?.abs_value();
+        let half = Decimal256::new(gap.atomics() / Uint256::from(2u8));
+        return lower.checked_add(SignedDecimal::from(half));
+    }
+    // Otherwise the sum does, and is halved rounding down
+    let sum = lower.checked_add(upper)?;
+    let half = RoundingMode::Floor.div(
+        sum.abs_value().atomics().into(),
+        Uint256::from(2u8).into(),
+        sum.is_gte_zero(),
+    )?;
+    let half = SignedDecimal::from(Decimal256::new(half));
+    Ok(if sum.is_lt_zero() { -half } else { half })
+}
+
+/// Mean of `values` after dropping `floor(len * trim)` values from each end,
+/// truncated toward zero. `values` are sorted in place, and `trim` must be
+/// below 0.5 so something is left.
+pub fn trimmed_mean(values: &mut [SignedDecimal], trim: Decimal256) -> CommonResult<SignedDecimal> {
+    require_values(values)?;
+    if trim >= Decimal256::percent(50) {
+        return Err(CommonError::Generic(format!(
+            "Trim fraction {trim} must be below 0.5"
+        )));
+    }
+    values.sort_unstable();
+    let cut = Uint128::try_from(Uint256::from(values.len() as u128) * trim)?.u128() as usize;
+    let kept = &values[cut..values.len() - cut];
+    let sum = kept
+        .iter()
+        .try_fold(SignedDecimal::zero(), |acc, x| acc.checked_add(*x))?;
+    sum.checked_div(SignedDecimal::from_uint128((kept.len() as u128).into()))
+}
+
+/// Median absolute deviation: the median of each value's distance from the
+/// median of `values`. `values` are sorted in place.
+pub fn mad(values: &mut [SignedDecimal]) -> CommonResult<SignedDecimal> {
+    let center = median(values)?;
+    let mut deviations = values
+        .iter()
+        .map(|x| x.checked_sub(center)?.checked_abs())
+        .collect::<CommonResult<Vec<_>>>()?;
+    median(&mut deviations)
+}
+
+/// Streaming estimate of a quantile, e.g. the 95th percentile of signed
+/// slippage, by the P² algorithm of Jain and Chlamtac. Only five markers are
+/// kept however many values are added, so the state suits contract storage.
+///
+/// Marker heights are adjusted in 18 decimal fixed point with truncating
+/// division, so estimates may differ from a floating point P² in the last
+/// digits.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct P2Quantile {
+    quantile: Decimal256,
+    count: u64,
+    /// Marker heights, or the sorted values until there are five
+    heights: Vec<SignedDecimal>,
+    /// Marker positions, counting from one
+    positions: [u64; 5],
+}
+
+impl P2Quantile {
+    /// Errors if `quantile` exceeds one
+    pub fn new(quantile: Decimal256) -> CommonResult<Self> {
+        if quantile > Decimal256::one() {
+            return Err(CommonError::Generic(format!(
+                "Quantile {quantile} exceeds one"
+            )));
+        }
+        Ok(Self {
+            quantile,
+            count: 0,
+            heights: vec![],
+            positions: [1, 2, 3, 4, 5],
+        })
+    }
+
+    /// Number of values added so far
+    pub fn count(&self) -> u64 {
+        self.count
+    }
+
+    pub fn add(&mut self, value: SignedDecimal) -> CommonResult<()> {
+        self.count += 1;
+        if self.heights.len() < 5 {
+            let i = self.heights.partition_point(|h| *h <= value);
+            self.heights.insert(i, value);
+            return Ok(());
+        }
+        // Index of the cell holding value, stretching the outer markers
+        let cell = if value < self.heights[0] {
+            self.heights[0] = value;
+            0
+        } else if value >= self.heights[4] {
+            self.heights[4] = value;
+            3
+        } else {
+            (1..4).find(|i| value < self.heights[*i]).unwrap_or(4) - 1
+        };
+        for position in &mut self.positions[cell + 1..] {
+            *position += 1;
+        }
+        for i in 1..4 {
+            let offset = self.desired_position(i)?.checked_sub(self.position(i))?;
+            let forward = if offset >= SignedDecimal::one()
+                && self.positions[i + 1] - self.positions[i] > 1
+            {
+                true
+            } else if offset <= -SignedDecimal::one()
+                && self.positions[i] - self.positions[i - 1] > 1
+            {
+                false
+            } else {
+                continue;
+            };
+            let height = self.parabolic(i, forward)?;
+            self.heights[i] = if self.heights[i - 1] < height && height < self.heights[i + 1] {
+                height
+            } else {
+                self.linear(i, forward)?
+            };
+            if forward {
+                self.positions[i] += 1;
+            } else {
+                self.positions[i] -= 1;
+            }
+        }
+        Ok(())
+    }
+
+    /// Current estimate, which is exact until five values have been added,
+    /// or `None` before the first
+    pub fn estimate(&self) -> Option<SignedDecimal> {
+        if self.heights.len() == 5 {
+            return Some(self.heights[2]);
+        }
+        // Sorted sample, so pick the value at rank floor((len - 1) * quantile)
+        let last = Uint256::from(self.heights.len().checked_sub(1)? as u128);
+        let rank = Uint128::try_from(last * self.quantile).ok()?.u128() as usize;
+        Some(self.heights[rank])
+    }
+
+    fn position(&self, i: usize) -> SignedDecimal {
+        SignedDecimal::from(self.positions[i])
+    }
+
+    /// Where marker `i` belongs: `1 + (count - 1) * f` with f being 0, p / 2,
+    /// p, (1 + p) / 2 and 1
+    fn desired_position(&self, i: usize) -> CommonResult<SignedDecimal> {
+        let p = SignedDecimal::from(self.quantile);
+        let one = SignedDecimal::one();
+        let two = SignedDecimal::from_int(2);
+        let fraction = match i {
+            0 => SignedDecimal::zero(),
+            1 => p.checked_div(two)?,
+            2 => p,
+            3 => one.checked_add(p)?.checked_div(two)?,
+            _ => one,
+        };
+        SignedDecimal::from(self.count - 1)
+            .checked_mul(fraction)?
+            .checked_add(one)
+    }
+
+    /// Height of marker `i` moved one position by the piecewise parabolic
+    /// formula
+    fn parabolic(&self, i: usize, forward: bool) -> CommonResult<SignedDecimal> {
+        let d = if forward {
+            SignedDecimal::one()
+        } else {
+            -SignedDecimal::one()
+        };
+        let (q0, q1, q2) = (self.heights[i - 1], self.heights[i], self.heights[i + 1]);
+        let (n0, n1, n2) = (self.position(i - 1), self.position(i), self.position(i + 1));
+        let upper = n1
+            .checked_sub(n0)?
+            .checked_add(d)?
+            .checked_mul(q2.checked_sub(q1)?)?
+            .checked_div(n2.checked_sub(n1)?)?;
+        let lower = n2
+            .checked_sub(n1)?
+            .checked_sub(d)?
+            .checked_mul(q1.checked_sub(q0)?)?
+            .checked_div(n1.checked_sub(n0)?)?;
+        q1.checked_add(
+            d.checked_mul(upper.checked_add(lower)?)?
+                .checked_div(n2.checked_sub(n0)?)?,
+        )
+    }
+
+    /// Height of marker `i` moved one position toward its neighbour, used
+    /// when the parabola would leave the neighbours' range
+    fn linear(&self, i: usize, forward: bool) -> CommonResult<SignedDecimal> {
+        let j = if forward { i + 1 } else { i - 1 };
+        let gap = self.positions[i].abs_diff(self.positions[j]);
+        self.heights[i].checked_add(
+            self.heights[j]
+                .checked_sub(self.heights[i])?
+                .checked_div(SignedDecimal::from(gap))?,
+        )
+    }
+}
+
+#[test]
+fn test_robust_aggregation() {
+    use std::str::FromStr;
+
+    let dec = |s: &str| SignedDecimal::from_str(s).unwrap();
+    let decs = |v: &[&str]| v.iter().map(|s| dec(s)).collect::<Vec<_>>();
+
+    assert_eq!(median(&mut decs(&["3", "-1", "2"])).unwrap(), dec("2"));
+    assert_eq!(
+        median(&mut decs(&["-0.5", "2", "-1", "4"])).unwrap(),
+        dec("0.75")
+    );
+    // Rounded down, whichever order the inputs come in
+    let mut odd_gap = decs(&["-0.000000000000000001", "0"]);
+    assert_eq!(median(&mut odd_gap).unwrap(), dec("-0.000000000000000001"));
+    odd_gap.reverse();
+    assert_eq!(median(&mut odd_gap).unwrap(), dec("-0.000000000000000001"));
+    assert_eq!(
+        median(&mut [SignedDecimal::MIN, SignedDecimal::MAX]).unwrap(),
+        dec("0")
+    );
+    assert_eq!(
+        median(&mut [SignedDecimal::MAX, SignedDecimal::MAX]).unwrap(),
+        SignedDecimal::MAX
+    );
+    assert_eq!(
+        median(&mut decs(&[
+            "-0.000000000000000003",
+            "0.000000000000000002"
+        ]))
+        .unwrap(),
+        dec("-0.000000000000000001")
+    );
+    assert!(median(&mut []).is_err());
+
+    // One outlier on each side is dropped with 10 values and a 0.1 trim
+    let mut premiums = decs(&[
+        "-50", "0.1", "0.2", "-0.1", "0", "0.3", "-0.2", "0.1", "0.2", "90",
+    ]);
+    assert_eq!(
+        trimmed_mean(&mut premiums, Decimal256::percent(10)).unwrap(),
+        dec("0.075")
+    );
+    assert_eq!(
+        trimmed_mean(&mut decs(&["1", "-2"]), Decimal256::zero()).unwrap(),
+        dec("-0.5")
+    );
+    assert!(trimmed_mean(&mut premiums, Decimal256::percent(50)).is_err());
+
+    assert_eq!(
+        mad(&mut decs(&["1", "1", "2", "2", "4", "6", "9"])).unwrap(),
+        dec("1")
+    );
+    assert_eq!(
+        mad(&mut decs(&["-3", "-1", "-2", "-40"])).unwrap(),
+        dec("1")
+    );
+}
+
+#[test]
+fn test_p2_quantile() {
+    use std::str::FromStr;
+
+    let dec = |s: &str| SignedDecimal::from_str(s).unwrap();
+    let mut p95 = P2Quantile::new(Decimal256::percent(95)).unwrap();
+    assert_eq!(p95.estimate(), None);
+
+    // Exact while there are fewer than five values
+    for x in ["3", "-1", "2"] {
+        p95.add(dec(x)).unwrap();
+    }
+    assert_eq!(p95.estimate(), Some(dec("2")));
+    let mut median = P2Quantile::new(Decimal256::percent(50)).unwrap();
+    for x in ["3", "-1", "2", "-5"] {
+        median.add(dec(x)).unwrap();
+    }
+    assert_eq!(median.estimate(), Some(dec("-1")));
+
+    // -50 to 49 in a scrambled order, with the 95th percentile near 45
+    let mut p95 = P2Quantile::new(Decimal256::percent(95)).unwrap();
+    let mut median = P2Quantile::new(Decimal256::percent(50)).unwrap();
+    for i in 1..=100i64 {
+        let x = SignedDecimal::from_int(i * 37 % 101 - 51);
+        p95.add(x).unwrap();
+        median.add(x).unwrap();
+    }
+    assert_eq!(p95.count(), 100);
+    let estimate = p95.estimate().unwrap();
+    assert!(estimate > dec("42") && estimate < dec("48"), "{estimate:?}");
+    let estimate = median.estimate().unwrap();
+    assert!(estimate > dec("-4") && estimate < dec("4"), "{estimate:?}");
+
+    let stored = serde_json::to_string(&p95).unwrap();
+    assert_eq!(serde_json::from_str::<P2Quantile>(&stored).unwrap(), p95);
+
+    assert!(P2Quantile::new(Decimal256::percent(101)).is_err());
+}
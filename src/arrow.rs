@@ -0,0 +1,151 @@
+//! Conversions with Arrow's `Decimal256` values and arrays, so indexers can
+//! bulk-export signed metrics to Parquet without going through strings.
+//!
+//! A value maps to its atomics at 18 decimal places, so the columns built
+//! here have scale 18 and precision 76, the most a `Decimal256` allows.
+
+use std::convert::TryFrom;
+
+use arrow_array::{Array, Decimal256Array};
+use arrow_buffer::i256;
+use cosmwasm_std::{ConversionOverflowError, Decimal256, Uint256};
+
+use crate::{
+    error::{CommonError, CommonResult},
+    signed_decimal::SignedDecimal,
+    signed_int::SignedInt,
+};
+
+/// Precision of the columns built by [`to_decimal256_array`]
+pub const PRECISION: u8 = 76;
+/// Scale of the columns built by [`to_decimal256_array`]
+pub const SCALE: i8 = SignedDecimal::DECIMAL_PLACES as i8;
+
+/// Atomics at scale 18. Fails with a conversion overflow when the atomics have
+/// more than 76 digits, which no Decimal256 column can hold.
+impl TryFrom<SignedDecimal> for i256 {
+    type Error = CommonError;
+
+    fn try_from(value: SignedDecimal) -> CommonResult<Self> {
+        let limit = Uint256::from(10u8).pow(u32::from(PRECISION));
+        let atomics = value.abs_value().atomics();
+        if atomics >= limit {
+            return Err(ConversionOverflowError::new(
+                "SignedDecimal",
+                "Decimal256(76, 18)",
+                value.to_string(),
+            )
+            .into());
+        }
+        let atomics = SignedInt {
+            value: atomics,
+            is_positive: value.is_gte_zero(),
+        };
+        Ok(i256::from_be_bytes(
+            atomics.to_twos_complement().to_be_bytes(),
+        ))
+    }
+}
+
+/// Reads the value as atomics at scale 18. Every i256 fits, so this is exact.
+impl From<i256> for SignedDecimal {
+    fn from(value: i256) -> Self {
+        let atomics = SignedInt::from_twos_complement(Uint256::from_be_bytes(value.to_be_bytes()));
+        SignedDecimal::from((Decimal256::new(atomics.value), atomics.is_positive))
+    }
+}
+
+/// A Decimal256 column of the values at precision 76 and scale 18, with
+/// `None` written as null
+pub fn to_decimal256_array(
+    values: impl IntoIterator<Item = Option<SignedDecimal>>,
+) -> CommonResult<Decimal256Array> {
+    let values = values
+        .into_iter()
+        .map(|value| value.map(i256::try_from).transpose())
+        .collect::<CommonResult<Vec<_>>>()?;
+    Decimal256Array::from_iter(values)
+        .with_precision_and_scale(PRECISION, SCALE)
+        .map_err(|e| CommonError::Generic(e.to_string()))
+}
+
+/// The values of a Decimal256 column at any scale, with nulls as `None`.
+///
+/// Fails rather than rounding when a value has digits beyond 18 decimal
+/// places, and with an overflow when rescaling leaves the SignedDecimal range.
+pub fn from_decimal256_array(array: &Decimal256Array) -> CommonResult<Vec<Option<SignedDecimal>>> {
+    let shift = i32::from(SCALE) - i32::from(array.scale());
+    (0..array.len())
+        .map(|i| {
+            if array.is_null(i) {
+                return Ok(None);
+            }
+            let raw = SignedDecimal::from(array.value(i));
+            let value = raw.mul_pow10(shift)?;
+            if shift < 0 && value.mul_pow10(-shift)? != raw {
+                return Err(CommonError::PrecisionLoss {
+                    value: array.value_as_string(i),
+                    decimal_places: SignedDecimal::DECIMAL_PLACES,
+                });
+            }
+            Ok(Some(value))
+        })
+        .collect()
+}
+
+#[test]
+fn test_arrow() {
+    use std::str::FromStr;
+
+    let dec = |s: &str| SignedDecimal::from_str(s).unwrap();
+
+    assert_eq!(
+        i256::try_from(dec("-1.5")).unwrap(),
+        i256::from_i128(-1_500_000_000_000_000_000)
+    );
+    for s in ["0", "-1.5", "0.000000000000000001", "-123456789.987654321"] {
+        assert_eq!(SignedDecimal::from(i256::try_from(dec(s)).unwrap()), dec(s));
+    }
+    let tick = dec("0.000000000000000001");
+    assert_eq!(
+        SignedDecimal::from(i256::MIN),
+        (-SignedDecimal::from(i256::MAX)).checked_sub(tick).unwrap()
+    );
+    assert!(matches!(
+        i256::try_from(SignedDecimal::MAX),
+        Err(CommonError::ConversionOverflow(_))
+    ));
+    assert!(i256::try_from(SignedDecimal::MIN).is_err());
+
+    let values = vec![Some(dec("2.5")), None, Some(dec("-0.000000000000000001"))];
+    let array = to_decimal256_array(values.clone()).unwrap();
+    assert_eq!((array.precision(), array.scale()), (76, 18));
+    assert_eq!(array.value_as_string(0), "2.500000000000000000");
+    assert!(array.is_null(1));
+    assert_eq!(from_decimal256_array(&array).unwrap(), values);
+    assert!(to_decimal256_array([Some(SignedDecimal::MAX)]).is_err());
+
+    // Columns written at other scales are rescaled exactly
+    let cents = Decimal256Array::from(vec![i256::from_i128(-1234)])
+        .with_precision_and_scale(10, 2)
+        .unwrap();
+    assert_eq!(
+        from_decimal256_array(&cents).unwrap(),
+        vec![Some(dec("-12.34"))]
+    );
+    let fine = Decimal256Array::from(vec![i256::from_i128(15), i256::from_i128(-10)])
+        .with_precision_and_scale(40, 19)
+        .unwrap();
+    assert_eq!(
+        from_decimal256_array(&fine),
+        Err(CommonError::PrecisionLoss {
+            value: "0.0000000000000000015".to_string(),
+            decimal_places: 18,
+        })
+    );
+    let fine = fine.slice(1, 1);
+    assert_eq!(
+        from_decimal256_array(&fine).unwrap(),
+        vec![Some(dec("-0.000000000000000001"))]
+    );
+}
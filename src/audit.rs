@@ -0,0 +1,97 @@
+use cosmwasm_std::Decimal256;
+
+use crate::{
+    error::CommonResult,
+    signed_decimal::{RoundingMode, SignedDecimal},
+};
+
+/// Signed change of a parameter from `old` to `new`, for migration audits
+/// and parameter-change events. Errors with an overflow when the change is
+/// beyond the SignedDecimal range, e.g. from `MIN` to `MAX`.
+pub fn diff_report(old: &SignedDecimal, new: &SignedDecimal) -> CommonResult<SignedDecimal> {
+    new.checked_sub(*old)
+}
+
+/// The change from `old` to `new` with its relative size, e.g. "+0.5 (+2.3%)"
+/// or "-1.25 (-50.0%)".
+///
+/// The change is exact and the percentage is rounded half up to one decimal
+/// place, so the same pair always renders the same text. The percentage is
+/// "n/a" when `old` is zero or the ratio is out of range, and the whole
+/// report errors like [`diff_report`] when the change is.
+pub fn format_diff(old: &SignedDecimal, new: &SignedDecimal) -> CommonResult<String> {
+    let delta = diff_report(old, new)?;
+    let percent = delta
+        .checked_div(SignedDecimal::from(old.abs_value()))
+        .and_then(|ratio| ratio.checked_mul(SignedDecimal::from_int(100)))
+        .and_then(|percent| {
+            percent.quantize(
+                Decimal256::permille(100),
+                Decimal256::zero(),
+                RoundingMode::HalfUp,
+            )
+        });
+    let percent = match percent {
+        Ok(percent) => {
+            let text = with_sign(percent);
+            if text.contains('.') {
+                format!("{text}%")
+            } else {
+                format!("{text}.0%")
+            }
+        }
+        Err(_) => "n/a".to_string(),
+    };
+    Ok(format!("{} ({})", with_sign(delta), percent))
+}
+
+/// Decimal string with a leading "+" for positive values
+fn with_sign(value: SignedDecimal) -> String {
+    if value.is_gt_zero() {
+        format!("+{}", value.to_string())
+    } else {
+        value.to_string()
+    }
+}
+
+#[test]
+fn test_format_diff() {
+    use std::str::FromStr;
+
+    use crate::error::CommonError;
+
+    let dec = |s: &str| SignedDecimal::from_str(s).unwrap();
+    let diff = |old: &str, new: &str| format_diff(&dec(old), &dec(new)).unwrap();
+
+    assert_eq!(diff_report(&dec("21.5"), &dec("22")).unwrap(), dec("0.5"));
+    assert_eq!(diff_report(&dec("-1"), &dec("-3")).unwrap(), dec("-2"));
+
+    assert_eq!(diff("21.5", "22"), "+0.5 (+2.3%)");
+    assert_eq!(diff("2.5", "1.25"), "-1.25 (-50.0%)");
+    assert_eq!(diff("0.3", "0.3"), "0.0 (0.0%)");
+    // Relative to the magnitude, so moving away from zero reads as growth
+    assert_eq!(diff("-2", "-3"), "-1 (-50.0%)");
+    assert_eq!(diff("-2", "1"), "+3 (+150.0%)");
+    // Rounds half up, away from zero for negative changes
+    assert_eq!(diff("1000", "1000.25"), "+0.25 (0.0%)");
+    assert_eq!(diff("1000", "1000.5"), "+0.5 (+0.1%)");
+    assert_eq!(diff("1000", "999.5"), "-0.5 (-0.1%)");
+    assert_eq!(diff("0", "0.05"), "+0.05 (n/a)");
+    assert_eq!(
+        format_diff(&dec("0.000000000000000001"), &SignedDecimal::MAX).unwrap(),
+        format!(
+            "+{} (n/a)",
+            SignedDecimal::MAX
+                .checked_sub(dec("0.000000000000000001"))
+                .unwrap()
+                .to_string()
+        )
+    );
+
+    // A change beyond the range is an error rather than a panic
+    assert!(matches!(
+        diff_report(&SignedDecimal::MIN, &SignedDecimal::MAX),
+        Err(CommonError::Overflow(_))
+    ));
+    assert!(format_diff(&SignedDecimal::MAX, &SignedDecimal::MIN).is_err());
+}
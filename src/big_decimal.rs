@@ -0,0 +1,136 @@
+//! Conversions with `bigdecimal::BigDecimal`, e.g. for off-chain services
+//! that keep their books in arbitrary precision, and as an exact reference
+//! to check this crate's rounding against.
+
+use std::convert::TryFrom;
+
+use bigdecimal::{
+    num_bigint::{BigInt, Sign},
+    BigDecimal,
+};
+use cosmwasm_std::{ConversionOverflowError, Decimal256, Uint256};
+use num_traits::Zero;
+
+use crate::{
+    error::{CommonError, CommonResult},
+    signed_decimal::SignedDecimal,
+};
+
+const DECIMAL_PLACES: i64 = SignedDecimal::DECIMAL_PLACES as i64;
+
+/// Exact, since every SignedDecimal is a BigDecimal with 18 decimal places
+impl From<SignedDecimal> for BigDecimal {
+    fn from(value: SignedDecimal) -> Self {
+        let sign = if value.is_lt_zero() {
+            Sign::Minus
+        } else {
+            Sign::Plus
+        };
+        let atomics = value.abs_value().atomics().to_be_bytes();
+        BigDecimal::new(BigInt::from_bytes_be(sign, &atomics), DECIMAL_PLACES)
+    }
+}
+
+/// Fails rather than rounding when the value has digits beyond 18 decimal
+/// places, and with a conversion overflow beyond the SignedDecimal range
+impl TryFrom<&BigDecimal> for SignedDecimal {
+    type Error = CommonError;
+
+    fn try_from(value: &BigDecimal) -> CommonResult<Self> {
+        let overflow =
+            || ConversionOverflowError::new("BigDecimal", "SignedDecimal", value.to_string());
+        if value.is_zero() {
+            return Ok(SignedDecimal::zero());
+        }
+        // Check the exponent before rescaling, since a value like 1e-10000000
+        // would otherwise cost a huge power of ten to reach 18 places
+        let (_, scale) = value.normalized().as_bigint_and_exponent();
+        if scale > DECIMAL_PLACES {
+            return Err(CommonError::PrecisionLoss {
+                value: value.to_string(),
+                decimal_places: SignedDecimal::DECIMAL_PLACES,
+            });
+        }
+        if scale < -78 {
+            return Err(overflow().into());
+        }
+        let (atomics, _) = value.with_scale(DECIMAL_PLACES).into_bigint_and_exponent();
+        let (sign, bytes) = atomics.to_bytes_be();
+        if bytes.len() > 32 {
+            return Err(overflow().into());
+        }
+        let mut magnitude = [0u8; 32];
+        magnitude[32 - bytes.len()..].copy_from_slice(&bytes);
+        Ok(SignedDecimal::from((
+            Decimal256::new(Uint256::from_be_bytes(magnitude)),
+            sign != Sign::Minus,
+        )))
+    }
+}
+
+impl TryFrom<BigDecimal> for SignedDecimal {
+    type Error = CommonError;
+
+    fn try_from(value: BigDecimal) -> CommonResult<Self> {
+        SignedDecimal::try_from(&value)
+    }
+}
+
+#[test]
+fn test_big_decimal() {
+    use std::str::FromStr;
+
+    let dec = |s: &str| SignedDecimal::from_str(s).unwrap();
+    let big = |s: &str| BigDecimal::from_str(s).unwrap();
+
+    for s in ["0", "-1.5", "0.000000000000000001", "123456789.987654321"] {
+        assert_eq!(BigDecimal::from(dec(s)), big(s));
+        assert_eq!(SignedDecimal::try_from(big(s)).unwrap(), dec(s));
+    }
+    for x in [SignedDecimal::MIN, SignedDecimal::MAX] {
+        assert_eq!(SignedDecimal::try_from(BigDecimal::from(x)).unwrap(), x);
+    }
+    assert_eq!(
+        SignedDecimal::try_from(big("-2.50e3")).unwrap(),
+        dec("-2500")
+    );
+    assert_eq!(
+        SignedDecimal::try_from(big("1.000000000000000000000")).unwrap(),
+        dec("1")
+    );
+
+    assert_eq!(
+        SignedDecimal::try_from(big("-0.0000000000000000001")),
+        Err(CommonError::PrecisionLoss {
+            value: "-1E-19".to_string(),
+            decimal_places: 18,
+        })
+    );
+    assert!(matches!(
+        SignedDecimal::try_from(big("1e-10000000")),
+        Err(CommonError::PrecisionLoss { .. })
+    ));
+    let beyond_max = BigDecimal::from(SignedDecimal::MAX) + big("0.000000000000000001");
+    assert!(matches!(
+        SignedDecimal::try_from(beyond_max),
+        Err(CommonError::ConversionOverflow(_))
+    ));
+    assert!(matches!(
+        SignedDecimal::try_from(big("1e10000000")),
+        Err(CommonError::ConversionOverflow(_))
+    ));
+
+    // As a reference, products and quotients truncate toward zero at 18
+    // places exactly as the exact result would
+    let (a, b) = (dec("-1.234567890123456789"), dec("0.333333333333333333"));
+    let exact = BigDecimal::from(a) * BigDecimal::from(b);
+    assert_eq!(
+        BigDecimal::from(a.checked_mul(b).unwrap()),
+        exact.with_scale(DECIMAL_PLACES)
+    );
+    let exact = BigDecimal::from(a) / BigDecimal::from(b);
+    assert_eq!(
+        BigDecimal::from(a.checked_div(b).unwrap()),
+        exact.with_scale(DECIMAL_PLACES)
+    );
+}
@@ -0,0 +1,90 @@
+//! Conversions with `num_bigint::BigInt`, for checking on-chain signed math
+//! against arbitrary precision results off-chain.
+
+use std::convert::TryFrom;
+
+use cosmwasm_std::{ConversionOverflowError, Uint256};
+use num_bigint::{BigInt, Sign};
+
+use crate::{
+    error::{CommonError, CommonResult},
+    signed_int::SignedInt,
+};
+
+/// Exact for every number. NaN has no BigInt form and becomes zero, as it
+/// does when saturating.
+impl From<SignedInt> for BigInt {
+    fn from(value: SignedInt) -> Self {
+        if value.is_nan() {
+            return BigInt::default();
+        }
+        let sign = if value.is_positive {
+            Sign::Plus
+        } else {
+            Sign::Minus
+        };
+        BigInt::from_bytes_be(sign, &value.value.to_be_bytes())
+    }
+}
+
+/// Fails with a conversion overflow when the magnitude exceeds 256 bits
+impl TryFrom<&BigInt> for SignedInt {
+    type Error = CommonError;
+
+    fn try_from(value: &BigInt) -> CommonResult<Self> {
+        let (sign, bytes) = value.to_bytes_be();
+        if bytes.len() > 32 {
+            return Err(
+                ConversionOverflowError::new("BigInt", "SignedInt", value.to_string()).into(),
+            );
+        }
+        let mut magnitude = [0u8; 32];
+        magnitude[32 - bytes.len()..].copy_from_slice(&bytes);
+        Ok(SignedInt {
+            value: Uint256::from_be_bytes(magnitude),
+            is_positive: sign != Sign::Minus,
+        })
+    }
+}
+
+impl TryFrom<BigInt> for SignedInt {
+    type Error = CommonError;
+
+    fn try_from(value: BigInt) -> CommonResult<Self> {
+        SignedInt::try_from(&value)
+    }
+}
+
+#[test]
+fn test_big_int() {
+    use std::str::FromStr;
+
+    let int = |s: &str| SignedInt::from_str(s).unwrap();
+    let big = |s: &str| BigInt::from_str(s).unwrap();
+
+    for s in ["0", "-1", "42", "-340282366920938463463374607431768211456"] {
+        assert_eq!(BigInt::from(int(s)), big(s));
+        assert_eq!(SignedInt::try_from(big(s)).unwrap(), int(s));
+    }
+    for x in [SignedInt::MIN, SignedInt::MAX] {
+        assert_eq!(SignedInt::try_from(BigInt::from(x)).unwrap(), x);
+    }
+    assert_eq!(BigInt::from(SignedInt::nan()), BigInt::default());
+    assert!(!SignedInt::try_from(big("-0")).unwrap().is_nan());
+
+    let beyond = BigInt::from(SignedInt::MAX) + 1u8;
+    assert!(matches!(
+        SignedInt::try_from(&beyond),
+        Err(CommonError::ConversionOverflow(_))
+    ));
+    assert!(SignedInt::try_from(-beyond).is_err());
+
+    // A reference for the checked ops, which must match wherever they succeed
+    let (a, b) = (int("-123456789012345678901234567890"), int("987654321"));
+    let exact = BigInt::from(a) * BigInt::from(b);
+    assert_eq!(BigInt::from(a.checked_mul(b).unwrap()), exact);
+    assert_eq!(
+        BigInt::from(a.checked_div(b).unwrap()),
+        BigInt::from(a) / BigInt::from(b)
+    );
+}
@@ -0,0 +1,257 @@
+use cosmwasm_std::Decimal256;
+use schemars::JsonSchema;
+use serde::{de, ser, Deserialize, Deserializer, Serialize};
+
+use crate::{
+    error::{CommonError, CommonResult},
+    serde_helpers::deserialize_bounded,
+    signed_decimal::SignedDecimal,
+};
+
+fn check_sign<T: ToString>(value: T, ok: bool, expected: &str) -> CommonResult<T> {
+    if !ok {
+        return Err(CommonError::WrongSign {
+            value: value.to_string(),
+            expected: expected.to_string(),
+        });
+    }
+    Ok(value)
+}
+
+/// SignedDecimal restricted to `[MIN_BPS, MAX_BPS]` basis points, for
+/// governance parameters whose range should be enforced when the message is
+/// decoded. `BoundedSignedDecimal<-500, 500>` accepts -0.05 through 0.05.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub struct BoundedSignedDecimal<const MIN_BPS: i64, const MAX_BPS: i64>(SignedDecimal);
+
+impl<const MIN_BPS: i64, const MAX_BPS: i64> BoundedSignedDecimal<MIN_BPS, MAX_BPS> {
+    pub fn new(value: SignedDecimal) -> CommonResult<Self> {
+        if value < Self::min() || value > Self::max() {
+            return Err(CommonError::OutOfRange {
+                value: value.to_string(),
+                min: Self::min().to_string(),
+                max: Self::max().to_string(),
+            });
+        }
+        Ok(Self(value))
+    }
+
+    pub fn min() -> SignedDecimal {
+        from_bps(MIN_BPS)
+    }
+
+    pub fn max() -> SignedDecimal {
+        from_bps(MAX_BPS)
+    }
+
+    pub fn value(&self) -> SignedDecimal {
+        self.0
+    }
+}
+
+fn from_bps(bps: i64) -> SignedDecimal {
+    let value = SignedDecimal::from(Decimal256::from_ratio(bps.unsigned_abs(), 10_000u64));
+    if bps < 0 {
+        -value
+    } else {
+        value
+    }
+}
+
+impl<const MIN_BPS: i64, const MAX_BPS: i64> From<BoundedSignedDecimal<MIN_BPS, MAX_BPS>>
+    for SignedDecimal
+{
+    fn from(bounded: BoundedSignedDecimal<MIN_BPS, MAX_BPS>) -> Self {
+        bounded.0
+    }
+}
+
+impl<const MIN_BPS: i64, const MAX_BPS: i64> TryFrom<SignedDecimal>
+    for BoundedSignedDecimal<MIN_BPS, MAX_BPS>
+{
+    type Error = CommonError;
+
+    fn try_from(value: SignedDecimal) -> Result<Self, Self::Error> {
+        Self::new(value)
+    }
+}
+
+/// Serializes as the inner decimal string
+impl<const MIN_BPS: i64, const MAX_BPS: i64> Serialize for BoundedSignedDecimal<MIN_BPS, MAX_BPS> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ser::Serializer,
+    {
+        self.0.serialize(serializer)
+    }
+}
+
+/// Deserializes a decimal string, rejecting values outside the bounds
+impl<'de, const MIN_BPS: i64, const MAX_BPS: i64> Deserialize<'de>
+    for BoundedSignedDecimal<MIN_BPS, MAX_BPS>
+{
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserialize_bounded(deserializer, Self::min(), Self::max()).map(Self)
+    }
+}
+
+impl<const MIN_BPS: i64, const MAX_BPS: i64> JsonSchema for BoundedSignedDecimal<MIN_BPS, MAX_BPS> {
+    fn schema_name() -> String {
+        format!("BoundedSignedDecimal_{MIN_BPS}_{MAX_BPS}")
+    }
+
+    fn json_schema(gen: &mut schemars::gen::SchemaGenerator) -> schemars::schema::Schema {
+        String::json_schema(gen)
+    }
+}
+
+/// A SignedDecimal or SignedInt that is zero or above, e.g. a fee. Both
+/// the constructor and Deserialize reject negative values.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Serialize)]
+#[serde(transparent)]
+pub struct NonNegative<T>(T);
+
+impl<T: Default + PartialOrd + ToString> NonNegative<T> {
+    pub fn new(value: T) -> CommonResult<Self> {
+        // Default is zero for both SignedDecimal and SignedInt
+        let ok = value >= T::default();
+        check_sign(value, ok, "non-negative").map(Self)
+    }
+}
+
+impl<T: Copy> NonNegative<T> {
+    pub fn value(&self) -> T {
+        self.0
+    }
+}
+
+impl<'de, T> Deserialize<'de> for NonNegative<T>
+where
+    T: Deserialize<'de> + Default + PartialOrd + ToString,
+{
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Self::new(T::deserialize(deserializer)?).map_err(de::Error::custom)
+    }
+}
+
+impl<T: JsonSchema> JsonSchema for NonNegative<T> {
+    fn schema_name() -> String {
+        format!("NonNegative_{}", T::schema_name())
+    }
+
+    fn json_schema(gen: &mut schemars::gen::SchemaGenerator) -> schemars::schema::Schema {
+        T::json_schema(gen)
+    }
+}
+
+/// A SignedDecimal or SignedInt that is zero or below, e.g. a rebate. Both
+/// the constructor and Deserialize reject positive values.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Serialize)]
+#[serde(transparent)]
+pub struct NonPositive<T>(T);
+
+impl<T: Default + PartialOrd + ToString> NonPositive<T> {
+    pub fn new(value: T) -> CommonResult<Self> {
+        let ok = value <= T::default();
+        check_sign(value, ok, "non-positive").map(Self)
+    }
+}
+
+impl<T: Copy> NonPositive<T> {
+    pub fn value(&self) -> T {
+        self.0
+    }
+}
+
+impl<'de, T> Deserialize<'de> for NonPositive<T>
+where
+    T: Deserialize<'de> + Default + PartialOrd + ToString,
+{
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Self::new(T::deserialize(deserializer)?).map_err(de::Error::custom)
+    }
+}
+
+impl<T: JsonSchema> JsonSchema for NonPositive<T> {
+    fn schema_name() -> String {
+        format!("NonPositive_{}", T::schema_name())
+    }
+
+    fn json_schema(gen: &mut schemars::gen::SchemaGenerator) -> schemars::schema::Schema {
+        T::json_schema(gen)
+    }
+}
+
+#[test]
+fn test_bounded_signed_decimal() {
+    use std::str::FromStr;
+
+    type FundingCap = BoundedSignedDecimal<-500, 250>;
+
+    let dec = |s: &str| SignedDecimal::from_str(s).unwrap();
+
+    assert_eq!(FundingCap::min(), dec("-0.05"));
+    assert_eq!(FundingCap::max(), dec("0.025"));
+    assert_eq!(FundingCap::new(dec("-0.05")).unwrap().value(), dec("-0.05"));
+    assert_eq!(
+        FundingCap::new(dec("0.03")),
+        Err(CommonError::OutOfRange {
+            value: "0.03".into(),
+            min: "-0.05".into(),
+            max: "0.025".into(),
+        })
+    );
+    assert!(FundingCap::try_from(dec("-0.0501")).is_err());
+
+    let cap: FundingCap = serde_json::from_str(r#""0.01""#).unwrap();
+    assert_eq!(SignedDecimal::from(cap), dec("0.01"));
+    assert_eq!(serde_json::to_string(&cap).unwrap(), r#""0.01""#);
+    let err = serde_json::from_str::<FundingCap>(r#""-1""#).unwrap_err();
+    assert!(err
+        .to_string()
+        .contains("-1 is outside the allowed range [-0.05, 0.025]"));
+}
+
+#[test]
+fn test_sign_wrappers() {
+    use std::str::FromStr;
+
+    let dec = |s: &str| SignedDecimal::from_str(s).unwrap();
+
+    assert_eq!(
+        NonNegative::new(dec("0.003")).unwrap().value(),
+        dec("0.003")
+    );
+    assert!(NonNegative::new(dec("0")).is_ok());
+    assert_eq!(
+        NonNegative::new(dec("-0.001")),
+        Err(CommonError::WrongSign {
+            value: "-0.001".into(),
+            expected: "non-negative".into(),
+        })
+    );
+    assert!(NonNegative::new(crate::signed_int::SignedInt::nan()).is_err());
+    assert_eq!(
+        NonPositive::new(dec("-0.001")).unwrap().value(),
+        dec("-0.001")
+    );
+    assert!(NonPositive::new(dec("0")).is_ok());
+    assert!(NonPositive::new(crate::signed_int::SignedInt::from_u128(1)).is_err());
+
+    let fee: NonNegative<SignedDecimal> = serde_json::from_str(r#""0.01""#).unwrap();
+    assert_eq!(serde_json::to_string(&fee).unwrap(), r#""0.01""#);
+    let err = serde_json::from_str::<NonNegative<SignedDecimal>>(r#""-0.01""#).unwrap_err();
+    assert!(err.to_string().contains("-0.01 must be non-negative"));
+    let rebate: NonPositive<SignedDecimal> = serde_json::from_str(r#""-0.0002""#).unwrap();
+    assert_eq!(rebate.value(), dec("-0.0002"));
+    assert!(serde_json::from_str::<NonPositive<SignedDecimal>>(r#""0.0002""#).is_err());
+}
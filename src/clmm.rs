@@ -0,0 +1,208 @@
+use cosmwasm_std::{Decimal256, Isqrt, Uint256, Uint512};
+
+use crate::{
+    error::{CommonError, CommonResult},
+    signed_decimal::{RoundingMode, SignedDecimal},
+    signed_int::SignedInt,
+};
+
+/// Square root of `price`, as used by concentrated liquidity pools.
+///
+/// The root is computed exactly in 512 bits and then rounded to 18 decimal
+/// places with `mode`, so the result is within 1e-18 of the true root (half
+/// that for `HalfUp`). Every non-negative price has a representable root.
+pub fn sqrt_price_from_price(
+    price: SignedDecimal,
+    mode: RoundingMode,
+) -> CommonResult<SignedDecimal> {
+    let price = non_negative(price, "Price")?;
+    // sqrt(atomics / 10^18) * 10^18 = sqrt(atomics * 10^18)
+    let n = price.atomics().full_mul(Decimal256::one().atomics());
+    let root = n.isqrt();
+    let remainder = n - root * root;
+    let round_up = !remainder.is_zero()
+        && match mode {
+            RoundingMode::Floor | RoundingMode::TowardZero => false,
+            RoundingMode::Ceil | RoundingMode::AwayFromZero => true,
+            // n is an integer, so n > root^2 + root exactly when
+            // sqrt(n) > root + 0.5, and there's never a tie
+            RoundingMode::HalfUp => remainder > root,
+        };
+    let root = Uint256::try_from(root)?;
+    let root = if round_up {
+        root.checked_add(Uint256::from_u128(1))?
+    } else {
+        root
+    };
+    Ok(SignedDecimal::from(Decimal256::new(root)))
+}
+
+/// Price whose square root is `sqrt_price`, the inverse of
+/// [`sqrt_price_from_price`].
+///
+/// The square is rounded to 18 decimal places with `mode`, so it is within
+/// 1e-18 of the exact square. A round trip from a price `p` is additionally
+/// off by up to about `2 * sqrt(p) * 1e-18` from rounding the root.
+pub fn price_from_sqrt_price(
+    sqrt_price: SignedDecimal,
+    mode: RoundingMode,
+) -> CommonResult<SignedDecimal> {
+    let sqrt_price = non_negative(sqrt_price, "Sqrt price")?.atomics();
+    let value = mode.div(
+        sqrt_price.full_mul(sqrt_price),
+        Uint512::from(Decimal256::one().atomics()),
+        true,
+    )?;
+    Ok(SignedDecimal::from(Decimal256::new(value)))
+}
+
+/// Signed amount of token0 added to a position between two sqrt prices when
+/// its liquidity changes by `liquidity`, i.e. `L * (sqrt_b - sqrt_a) /
+/// (sqrt_a * sqrt_b)`. The prices may be given in either order.
+///
+/// As in Uniswap v3, adding liquidity rounds the amount owed to the pool up
+/// and removing it rounds the amount paid out down, so the pool never loses
+/// to rounding. Products are formed in 512 bits, so only a result that
+/// doesn't fit in 256 bits fails.
+pub fn get_amount0_delta(
+    sqrt_price_a: SignedDecimal,
+    sqrt_price_b: SignedDecimal,
+    liquidity: SignedInt,
+) -> CommonResult<SignedInt> {
+    let (lower, upper) = sorted(sqrt_price_a, sqrt_price_b)?;
+    // L * (upper - lower) / upper can't exceed L, so it fits before scaling
+    let value = RoundingMode::Ceil.div(
+        liquidity.value.full_mul(upper - lower),
+        upper.into(),
+        liquidity.is_positive,
+    )?;
+    let value = RoundingMode::Ceil.div(
+        value.full_mul(Decimal256::one().atomics()),
+        lower.into(),
+        liquidity.is_positive,
+    )?;
+    Ok(with_sign_of(value, liquidity))
+}
+
+/// Signed amount of token1 added to a position between two sqrt prices when
+/// its liquidity changes by `liquidity`, i.e. `L * (sqrt_b - sqrt_a)`, rounded
+/// like [`get_amount0_delta`].
+pub fn get_amount1_delta(
+    sqrt_price_a: SignedDecimal,
+    sqrt_price_b: SignedDecimal,
+    liquidity: SignedInt,
+) -> CommonResult<SignedInt> {
+    let (lower, upper) = sorted(sqrt_price_a, sqrt_price_b)?;
+    let value = RoundingMode::Ceil.div(
+        liquidity.value.full_mul(upper - lower),
+        Decimal256::one().atomics().into(),
+        liquidity.is_positive,
+    )?;
+    Ok(with_sign_of(value, liquidity))
+}
+
+/// Atomics of two sqrt prices, smallest first
+fn sorted(a: SignedDecimal, b: SignedDecimal) -> CommonResult<(Uint256, Uint256)> {
+    let a = non_negative(a, "Sqrt price")?.atomics();
+    let b = non_negative(b, "Sqrt price")?.atomics();
+    Ok(if a <= b { (a, b) } else { (b, a) })
+}
+
+fn with_sign_of(value: Uint256, liquidity: SignedInt) -> SignedInt {
+    SignedInt {
+        value,
+        is_positive: liquidity.is_positive || value.is_zero(),
+    }
+}
+
+fn non_negative(value: SignedDecimal, name: &str) -> CommonResult<Decimal256> {
+    if value.is_lt_zero() {
+        return Err(CommonError::Generic(format!(
+            "{} must not be negative, got {}",
+            name,
+            value.to_string()
+        )));
+    }
+    Ok(value.abs_value())
+}
+
+#[test]
+fn test_sqrt_price() {
+    use std::str::FromStr;
+
+    let dec = |s: &str| SignedDecimal::from_str(s).unwrap();
+    let sqrt = |s: &str, mode| sqrt_price_from_price(dec(s), mode).unwrap();
+
+    assert_eq!(sqrt("0", RoundingMode::Ceil), dec("0"));
+    assert_eq!(sqrt("4", RoundingMode::Ceil), dec("2"));
+    assert_eq!(sqrt("0.0001", RoundingMode::Floor), dec("0.01"));
+    assert_eq!(sqrt("2", RoundingMode::Floor), dec("1.414213562373095048"));
+    assert_eq!(sqrt("2", RoundingMode::Ceil), dec("1.414213562373095049"));
+    assert_eq!(sqrt("2", RoundingMode::HalfUp), dec("1.414213562373095049"));
+    assert_eq!(sqrt("3", RoundingMode::HalfUp), dec("1.732050807568877294"));
+    assert_eq!(
+        sqrt("0.000000000000000001", RoundingMode::Floor),
+        dec("0.000000001")
+    );
+    // The largest price still has a root
+    let max = SignedDecimal::from(Decimal256::MAX);
+    assert!(sqrt_price_from_price(max, RoundingMode::Ceil).is_ok());
+    assert!(sqrt_price_from_price(dec("-1"), RoundingMode::Floor).is_err());
+
+    let square = |s: &str, mode| price_from_sqrt_price(dec(s), mode).unwrap();
+    assert_eq!(square("1.5", RoundingMode::Floor), dec("2.25"));
+    assert_eq!(
+        square("1.414213562373095048", RoundingMode::Floor),
+        dec("1.999999999999999997")
+    );
+    assert_eq!(
+        square("1.414213562373095048", RoundingMode::Ceil),
+        dec("1.999999999999999998")
+    );
+    assert_eq!(
+        square("0.000000001", RoundingMode::Floor),
+        dec("0.000000000000000001")
+    );
+    assert!(price_from_sqrt_price(max, RoundingMode::Floor).is_err());
+    assert!(price_from_sqrt_price(dec("-0.5"), RoundingMode::Floor).is_err());
+}
+
+#[test]
+fn test_amount_deltas() {
+    use std::str::FromStr;
+
+    let dec = |s: &str| SignedDecimal::from_str(s).unwrap();
+    let int = |s: &str| SignedInt::from_str(s).unwrap();
+
+    assert_eq!(
+        get_amount0_delta(dec("1"), dec("2"), int("1000")).unwrap(),
+        int("500")
+    );
+    assert_eq!(
+        get_amount1_delta(dec("2"), dec("1"), int("-1000")).unwrap(),
+        int("-1000")
+    );
+
+    // Deposits round up, withdrawals round toward zero
+    let (a, b) = (dec("1"), dec("1.5"));
+    assert_eq!(get_amount0_delta(a, b, int("1001")).unwrap(), int("334"));
+    assert_eq!(get_amount0_delta(a, b, int("-1001")).unwrap(), int("-333"));
+    assert_eq!(get_amount1_delta(a, b, int("1001")).unwrap(), int("501"));
+    assert_eq!(get_amount1_delta(b, a, int("-1001")).unwrap(), int("-500"));
+
+    // Small withdrawals round to a positive zero
+    let dust = get_amount1_delta(a, dec("1.000000000000000001"), int("-1")).unwrap();
+    assert_eq!(dust, SignedInt::zero());
+    assert!(dust.is_positive);
+
+    // The product of a huge liquidity and a wide range doesn't overflow
+    let huge = SignedInt {
+        value: Uint256::MAX,
+        is_positive: false,
+    };
+    let amount = get_amount1_delta(dec("0.5"), dec("0.75"), huge).unwrap();
+    assert_eq!(amount.value, Uint256::MAX / Uint256::from_u128(4));
+    assert!(get_amount1_delta(dec("0"), dec("2"), huge).is_err());
+    assert!(get_amount0_delta(dec("0"), dec("1"), int("1")).is_err());
+    assert!(get_amount0_delta(dec("-1"), dec("1"), int("1")).is_err());
+}
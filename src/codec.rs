@@ -0,0 +1,538 @@
+use std::marker::PhantomData;
+
+use cosmwasm_std::{Binary, Decimal256, StdError, Storage, Uint256};
+use serde::{de, ser, Deserialize, Deserializer, Serialize};
+
+use crate::{
+    error::{CommonError, CommonResult},
+    signed_decimal::SignedDecimal,
+    signed_int::SignedInt,
+};
+
+/// Length of the compact encoding: one sign byte and a 32 byte magnitude
+pub const COMPACT_LEN: usize = 33;
+
+/// Length of [`CompactCodec::to_sort_key_string`]: a sign digit and the 78
+/// digits of `Uint256::MAX`
+pub const SORT_KEY_LEN: usize = 79;
+
+/// Fixed width binary encoding for raw storage.
+///
+/// The first byte is 1 for non-negative values and 0 for negative ones,
+/// followed by the big-endian magnitude (atomics for SignedDecimal).
+pub trait CompactCodec: Sized {
+    fn to_compact(&self) -> [u8; COMPACT_LEN];
+
+    fn from_compact(bytes: &[u8]) -> CommonResult<Self>;
+
+    /// Constant-time equality of the compact encodings, for comparing secret
+    /// values such as sealed bids. Every byte is inspected whatever the
+    /// inputs, in the style of `subtle::ConstantTimeEq`.
+    fn ct_eq(&self, other: &Self) -> bool {
+        let (a, b) = (self.to_compact(), other.to_compact());
+        let diff = a
+            .iter()
+            .zip(b.iter())
+            .fold(0u8, |acc, (x, y)| acc | (x ^ y));
+        std::hint::black_box(diff) == 0
+    }
+
+    /// Fixed width string of [`SORT_KEY_LEN`] ASCII characters that sorts
+    /// lexicographically in the same order as the values, for stores that
+    /// can only order strings.
+    ///
+    /// Non-negative values are "1" and the zero-padded magnitude, negative
+    /// ones "0" and the padded complement `Uint256::MAX - magnitude`.
+    fn to_sort_key_string(&self) -> String {
+        let bytes = self.to_compact();
+        let mut magnitude = [0u8; 32];
+        magnitude.copy_from_slice(&bytes[1..]);
+        let magnitude = Uint256::from_be_bytes(magnitude);
+        let (sign, digits) = if bytes[0] == 1 {
+            ('1', magnitude)
+        } else {
+            ('0', Uint256::MAX - magnitude)
+        };
+        format!("{sign}{digits:0>width$}", width = SORT_KEY_LEN - 1)
+    }
+
+    /// Compact encoding whose lexicographic byte order is the numeric
+    /// order, for storage keys scanned by range.
+    ///
+    /// The sign byte leads as in [`CompactCodec::to_compact`], and negative
+    /// magnitudes are bitwise complemented so larger ones sort first.
+    fn to_sortable_bytes(&self) -> [u8; COMPACT_LEN] {
+        let mut bytes = self.to_compact();
+        if bytes[0] == 0 {
+            bytes[1..].iter_mut().for_each(|b| *b = !*b);
+        }
+        bytes
+    }
+
+    /// Inverse of [`CompactCodec::to_sortable_bytes`]
+    fn from_sortable_bytes(bytes: &[u8]) -> CommonResult<Self> {
+        let mut bytes = bytes.to_vec();
+        if bytes.first() == Some(&0) {
+            bytes[1..].iter_mut().for_each(|b| *b = !*b);
+        }
+        Self::from_compact(&bytes)
+    }
+}
+
+fn encode(is_positive: bool, magnitude: Uint256) -> [u8; COMPACT_LEN] {
+    let mut bytes = [0u8; COMPACT_LEN];
+    bytes[0] = is_positive as u8;
+    bytes[1..].copy_from_slice(&magnitude.to_be_bytes());
+    bytes
+}
+
+fn decode(bytes: &[u8]) -> CommonResult<(bool, Uint256)> {
+    let bytes: &[u8; COMPACT_LEN] = bytes.try_into().map_err(|_| {
+        CommonError::Generic(format!(
+            "Compact encoding must be {} bytes, got {}",
+            COMPACT_LEN,
+            bytes.len()
+        ))
+    })?;
+    let is_positive = match bytes[0] {
+        0 => false,
+        1 => true,
+        b => {
+            return Err(CommonError::Generic(format!(
+                "Invalid sign byte {b} in compact encoding"
+            )))
+        }
+    };
+    let mut magnitude = [0u8; 32];
+    magnitude.copy_from_slice(&bytes[1..]);
+    Ok((is_positive, Uint256::from_be_bytes(magnitude)))
+}
+
+impl CompactCodec for SignedInt {
+    fn to_compact(&self) -> [u8; COMPACT_LEN] {
+        encode(self.is_positive, self.value)
+    }
+
+    fn from_compact(bytes: &[u8]) -> CommonResult<Self> {
+        let (is_positive, value) = decode(bytes)?;
+        Ok(Self { value, is_positive })
+    }
+}
+
+impl CompactCodec for SignedDecimal {
+    fn to_compact(&self) -> [u8; COMPACT_LEN] {
+        encode(self.is_gte_zero(), self.abs_value().atomics())
+    }
+
+    fn from_compact(bytes: &[u8]) -> CommonResult<Self> {
+        let (is_positive, atomics) = decode(bytes)?;
+        let value = SignedDecimal::from(Decimal256::new(atomics));
+        Ok(if is_positive { value } else { -value })
+    }
+}
+
+impl SignedInt {
+    /// Fixed width big-endian bytes, which are the [`CompactCodec`] encoding,
+    /// so every value including NaN round-trips through
+    /// [`SignedInt::from_be_bytes`]
+    pub fn to_be_bytes(&self) -> [u8; COMPACT_LEN] {
+        self.to_compact()
+    }
+
+    /// Inverse of [`SignedInt::to_be_bytes`], erroring on a bad sign byte
+    pub fn from_be_bytes(bytes: [u8; COMPACT_LEN]) -> CommonResult<Self> {
+        Self::from_compact(&bytes)
+    }
+}
+
+impl SignedDecimal {
+    /// Fixed width big-endian bytes of the sign and atomics, which are the
+    /// [`CompactCodec`] encoding. Equal values give equal bytes, so they can
+    /// be hashed.
+    pub fn to_be_bytes(&self) -> [u8; COMPACT_LEN] {
+        self.to_compact()
+    }
+
+    /// Inverse of [`SignedDecimal::to_be_bytes`], erroring on a bad sign byte
+    pub fn from_be_bytes(bytes: [u8; COMPACT_LEN]) -> CommonResult<Self> {
+        Self::from_compact(&bytes)
+    }
+}
+
+/// Wrapper serializing the inner value as the base64 of its [`CompactCodec`]
+/// encoding, 46 bytes in JSON, for messages that carry the compact form.
+///
+/// Storage goes through JSON too, so state should use [`CompactItem`] or
+/// [`CompactMap`], which write the 33 bytes as they are.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Compact<T>(pub T);
+
+impl<T> From<T> for Compact<T> {
+    fn from(value: T) -> Self {
+        Self(value)
+    }
+}
+
+impl From<Compact<SignedInt>> for SignedInt {
+    fn from(compact: Compact<SignedInt>) -> Self {
+        compact.0
+    }
+}
+
+impl From<Compact<SignedDecimal>> for SignedDecimal {
+    fn from(compact: Compact<SignedDecimal>) -> Self {
+        compact.0
+    }
+}
+
+impl<T: CompactCodec> Serialize for Compact<T> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ser::Serializer,
+    {
+        Binary::from(self.0.to_compact()).serialize(serializer)
+    }
+}
+
+impl<'de, T: CompactCodec> Deserialize<'de> for Compact<T> {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let bytes = Binary::deserialize(deserializer)?;
+        T::from_compact(bytes.as_slice())
+            .map(Compact)
+            .map_err(|e| de::Error::custom(e.to_string()))
+    }
+}
+
+/// Single value stored as its raw [`CompactCodec`] encoding, where
+/// cw-storage-plus' `Item` would store the JSON string. Every value takes
+/// [`COMPACT_LEN`] bytes, which saves write gas on anything longer than a few
+/// digits.
+pub struct CompactItem<'a, T> {
+    key: &'a [u8],
+    value: PhantomData<T>,
+}
+
+impl<'a, T: CompactCodec> CompactItem<'a, T> {
+    pub const fn new(key: &'a str) -> Self {
+        Self {
+            key: key.as_bytes(),
+            value: PhantomData,
+        }
+    }
+
+    pub fn save(&self, store: &mut dyn Storage, value: &T) {
+        store.set(self.key, &value.to_compact());
+    }
+
+    /// Errors with not found when nothing was saved
+    pub fn load(&self, store: &dyn Storage) -> CommonResult<T> {
+        self.may_load(store)?
+            .ok_or_else(|| StdError::not_found(std::any::type_name::<T>()).into())
+    }
+
+    pub fn may_load(&self, store: &dyn Storage) -> CommonResult<Option<T>> {
+        store
+            .get(self.key)
+            .map(|bytes| T::from_compact(&bytes))
+            .transpose()
+    }
+
+    pub fn remove(&self, store: &mut dyn Storage) {
+        store.remove(self.key);
+    }
+}
+
+/// Values under byte keys stored as their raw [`CompactCodec`] encoding.
+///
+/// Keys are laid out like cw-storage-plus' `Map<&[u8], _>`, the namespace
+/// with a two byte length prefix followed by the key, so only the value
+/// encoding differs.
+pub struct CompactMap<'a, T> {
+    namespace: &'a [u8],
+    value: PhantomData<T>,
+}
+
+impl<'a, T: CompactCodec> CompactMap<'a, T> {
+    pub const fn new(namespace: &'a str) -> Self {
+        Self {
+            namespace: namespace.as_bytes(),
+            value: PhantomData,
+        }
+    }
+
+    fn storage_key(&self, key: &[u8]) -> Vec<u8> {
+        let mut storage_key = Vec::with_capacity(2 + self.namespace.len() + key.len());
+        storage_key.extend_from_slice(&(self.namespace.len() as u16).to_be_bytes());
+        storage_key.extend_from_slice(self.namespace);
+        storage_key.extend_from_slice(key);
+        storage_key
+    }
+
+    pub fn save(&self, store: &mut dyn Storage, key: &[u8], value: &T) {
+        store.set(&self.storage_key(key), &value.to_compact());
+    }
+
+    /// Errors with not found when nothing was saved under `key`
+    pub fn load(&self, store: &dyn Storage, key: &[u8]) -> CommonResult<T> {
+        self.may_load(store, key)?
+            .ok_or_else(|| StdError::not_found(std::any::type_name::<T>()).into())
+    }
+
+    pub fn may_load(&self, store: &dyn Storage, key: &[u8]) -> CommonResult<Option<T>> {
+        store
+            .get(&self.storage_key(key))
+            .map(|bytes| T::from_compact(&bytes))
+            .transpose()
+    }
+
+    pub fn remove(&self, store: &mut dyn Storage, key: &[u8]) {
+        store.remove(&self.storage_key(key));
+    }
+}
+
+#[test]
+fn test_compact_codec() {
+    use std::str::FromStr;
+
+    let ints = [
+        "0",
+        "-1",
+        "42",
+        "-115792089237316195423570985008687907853269984665640564039457584007913129639935",
+    ];
+    for s in ints {
+        let x = SignedInt::from_str(s).unwrap();
+        assert_eq!(SignedInt::from_compact(&x.to_compact()).unwrap(), x);
+
+        let stored = serde_json::to_vec(&Compact(x)).unwrap();
+        assert_eq!(stored.len(), 46);
+        let back: Compact<SignedInt> = serde_json::from_slice(&stored).unwrap();
+        assert_eq!(SignedInt::from(back), x);
+    }
+    let nan = SignedInt::nan();
+    assert!(SignedInt::from_compact(&nan.to_compact()).unwrap().is_nan());
+
+    let decimals = [
+        "0",
+        "-0.000000000000000001",
+        "1.5",
+        "-123456789012345678901234.123456789012345678",
+    ];
+    for s in decimals {
+        let x = SignedDecimal::from_str(s).unwrap();
+        assert_eq!(SignedDecimal::from_compact(&x.to_compact()).unwrap(), x);
+
+        let back: Compact<SignedDecimal> =
+            serde_json::from_slice(&serde_json::to_vec(&Compact(x)).unwrap()).unwrap();
+        assert_eq!(SignedDecimal::from(back), x);
+    }
+    assert_eq!(
+        SignedDecimal::from_compact(&[0u8; COMPACT_LEN]).unwrap(),
+        SignedDecimal::from_str("0").unwrap()
+    );
+
+    // Storage size against the JSON form
+    let big = SignedInt::MIN;
+    assert!(serde_json::to_vec(&big).unwrap().len() > 100);
+    assert_eq!(serde_json::to_vec(&Compact(big)).unwrap().len(), 46);
+
+    assert!(SignedInt::from_compact(&[1u8; 32]).is_err());
+    assert!(SignedInt::from_compact(&[2u8; COMPACT_LEN]).is_err());
+}
+
+#[test]
+fn test_compact_storage() {
+    use std::str::FromStr;
+
+    use cosmwasm_std::testing::MockStorage;
+
+    let mut storage = MockStorage::new();
+    let item: CompactItem<SignedInt> = CompactItem::new("total");
+    assert_eq!(item.may_load(&storage).unwrap(), None);
+    assert!(matches!(item.load(&storage), Err(CommonError::Std(_))));
+    item.save(&mut storage, &SignedInt::MIN);
+    assert_eq!(storage.get(b"total").unwrap().len(), COMPACT_LEN);
+    assert_eq!(item.load(&storage).unwrap(), SignedInt::MIN);
+    item.remove(&mut storage);
+    assert_eq!(item.may_load(&storage).unwrap(), None);
+
+    let dec = |s: &str| SignedDecimal::from_str(s).unwrap();
+    let map: CompactMap<SignedDecimal> = CompactMap::new("prices");
+    map.save(&mut storage, b"atom", &dec("-12.5"));
+    assert_eq!(map.load(&storage, b"atom").unwrap(), dec("-12.5"));
+    assert_eq!(map.may_load(&storage, b"osmo").unwrap(), None);
+
+    // Same keys as cw-storage-plus, so only the value encoding changes
+    let json: cw_storage_plus::Map<&[u8], SignedDecimal> = cw_storage_plus::Map::new("prices");
+    json.save(&mut storage, b"osmo", &dec("0.25")).unwrap();
+    assert_eq!(
+        map.load(&storage, b"osmo").map_err(|e| e.to_string()),
+        Err("Compact encoding must be 33 bytes, got 6".to_string())
+    );
+    map.remove(&mut storage, b"atom");
+    assert!(json.may_load(&storage, b"atom").unwrap().is_none());
+}
+
+#[test]
+fn test_compact_storage_gas() {
+    use std::str::FromStr;
+
+    use cosmwasm_std::testing::MockStorage;
+    use serde::de::DeserializeOwned;
+
+    /// Write gas of a value through cw-storage-plus and through
+    /// [`CompactItem`], under the Cosmos SDK's default KV store config: a flat
+    /// 2000 plus 30 per byte of key and value
+    fn gas<T: Serialize + DeserializeOwned + CompactCodec>(value: &T) -> (usize, usize) {
+        let mut storage = MockStorage::new();
+        let write_gas = |storage: &MockStorage| 2000 + 30 * (1 + storage.get(b"x").unwrap().len());
+        cw_storage_plus::Item::new("x")
+            .save(&mut storage, value)
+            .unwrap();
+        let json = write_gas(&storage);
+        CompactItem::new("x").save(&mut storage, value);
+        (json, write_gas(&storage))
+    }
+
+    // Every compact write costs the same, which beats JSON for all but small
+    // values
+    let dec = |s: &str| SignedDecimal::from_str(s).unwrap();
+    assert_eq!(gas(&dec("-1.5")), (2210, 3020));
+    assert_eq!(gas(&dec("-1234.567890123456789")), (2720, 3020));
+    assert_eq!(gas(&SignedDecimal::MIN), (4490, 3020));
+    assert_eq!(gas(&SignedInt::from_str("-1").unwrap()), (3020, 3020));
+    assert_eq!(gas(&SignedInt::MIN), (5330, 3020));
+}
+
+#[test]
+fn test_ct_eq() {
+    use std::str::FromStr;
+
+    let dec = |s: &str| SignedDecimal::from_str(s).unwrap();
+    assert!(dec("-1.5").ct_eq(&dec("-1.50")));
+    assert!(dec("-0").ct_eq(&dec("0")));
+    assert!(!dec("1.5").ct_eq(&dec("-1.5")));
+    assert!(!dec("1.5").ct_eq(&dec("1.500000000000000001")));
+
+    let int = |s: &str| SignedInt::from_str(s).unwrap();
+    assert!(int("-7").ct_eq(&int("-7")));
+    assert!(!int("7").ct_eq(&int("-7")));
+    assert!(!SignedInt::nan().ct_eq(&int("0")));
+}
+
+#[test]
+fn test_sort_key_string() {
+    use std::str::FromStr;
+
+    let values = [
+        "-115792089237316195423570985008687907853269984665.640564039457584007",
+        "-1000",
+        "-999.5",
+        "-1",
+        "-0.000000000000000001",
+        "0",
+        "0.000000000000000001",
+        "2",
+        "10",
+        "115792089237316195423570985008687907853269984665.640564039457584007",
+    ];
+    let keys: Vec<String> = values
+        .iter()
+        .map(|s| SignedDecimal::from_str(s).unwrap().to_sort_key_string())
+        .collect();
+    assert!(keys.iter().all(|k| k.len() == SORT_KEY_LEN));
+    assert!(keys.windows(2).all(|w| w[0] < w[1]));
+    assert_eq!(keys[5], format!("1{}", "0".repeat(78)));
+
+    let int = |s: &str| SignedInt::from_str(s).unwrap().to_sort_key_string();
+    assert!(int("-11") < int("-2"));
+    assert!(int("-2") < SignedInt::nan().to_sort_key_string());
+    assert!(SignedInt::nan().to_sort_key_string() < int("0"));
+    assert!(int("9") < int("10"));
+}
+
+#[test]
+fn test_be_bytes() {
+    use std::str::FromStr;
+
+    let int = SignedInt::from_str("-258").unwrap();
+    let bytes = int.to_be_bytes();
+    assert_eq!(bytes[0], 0);
+    assert_eq!(bytes[31..], [1, 2]);
+    assert_eq!(SignedInt::from_be_bytes(bytes).unwrap(), int);
+    for x in [SignedInt::MIN, SignedInt::MAX, SignedInt::zero()] {
+        assert_eq!(SignedInt::from_be_bytes(x.to_be_bytes()).unwrap(), x);
+    }
+    assert!(SignedInt::from_be_bytes(SignedInt::nan().to_be_bytes())
+        .unwrap()
+        .is_nan());
+
+    let dec = |s: &str| SignedDecimal::from_str(s).unwrap();
+    for x in [
+        dec("-1.5"),
+        dec("0"),
+        SignedDecimal::MIN,
+        SignedDecimal::MAX,
+    ] {
+        assert_eq!(SignedDecimal::from_be_bytes(x.to_be_bytes()).unwrap(), x);
+    }
+    assert_eq!(dec("-0").to_be_bytes(), dec("0.000").to_be_bytes());
+
+    let mut bad = int.to_be_bytes();
+    bad[0] = 2;
+    assert!(SignedInt::from_be_bytes(bad).is_err());
+    assert!(SignedDecimal::from_be_bytes(bad).is_err());
+}
+
+#[test]
+fn test_sortable_bytes() {
+    use std::str::FromStr;
+
+    let values = [
+        "-115792089237316195423570985008687907853269984665.640564039457584007",
+        "-256",
+        "-255.5",
+        "-1",
+        "-0.000000000000000001",
+        "0",
+        "0.000000000000000001",
+        "255",
+        "256",
+        "115792089237316195423570985008687907853269984665.640564039457584007",
+    ];
+    let values: Vec<_> = values
+        .iter()
+        .map(|s| SignedDecimal::from_str(s).unwrap())
+        .collect();
+    let keys: Vec<_> = values.iter().map(|x| x.to_sortable_bytes()).collect();
+    assert!(keys.windows(2).all(|w| w[0] < w[1]));
+    for (x, key) in values.iter().zip(&keys) {
+        assert_eq!(SignedDecimal::from_sortable_bytes(key).unwrap(), *x);
+    }
+
+    // Ranges over a storage map come back in numeric order
+    let mut storage = cosmwasm_std::testing::MockStorage::new();
+    let map: cw_storage_plus::Map<&[u8], SignedDecimal> = cw_storage_plus::Map::new("values");
+    for x in values.iter().rev() {
+        map.save(&mut storage, &x.to_sortable_bytes(), x).unwrap();
+    }
+    let scanned: Vec<_> = map
+        .range(&storage, None, None, cosmwasm_std::Order::Ascending)
+        .map(|item| item.unwrap().1)
+        .collect();
+    assert_eq!(scanned, values);
+
+    let int = |s: &str| SignedInt::from_str(s).unwrap();
+    let nan = SignedInt::nan().to_sortable_bytes();
+    assert!(int("-1").to_sortable_bytes() < nan);
+    assert!(nan < int("0").to_sortable_bytes());
+    assert!(SignedInt::from_sortable_bytes(&nan).unwrap().is_nan());
+    assert_eq!(
+        SignedInt::from_sortable_bytes(&SignedInt::MIN.to_sortable_bytes()).unwrap(),
+        SignedInt::MIN
+    );
+    assert!(SignedInt::from_sortable_bytes(&[0u8; 5]).is_err());
+}
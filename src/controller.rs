@@ -0,0 +1,226 @@
+use cosmwasm_std::Decimal256;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    error::{CommonError, CommonResult},
+    signed_decimal::SignedDecimal,
+};
+
+/// Proportional controller, producing `gain * (target - observed)` clamped to
+/// `[min, max]`, e.g. a funding rate steering a market price toward its peg.
+///
+/// Saturates rather than erroring, so an extreme observation yields the
+/// bound it points at.
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, JsonSchema)]
+pub struct Proportional {
+    pub gain: SignedDecimal,
+    pub min: SignedDecimal,
+    pub max: SignedDecimal,
+}
+
+impl Proportional {
+    /// Errors if `min` exceeds `max`
+    pub fn new(gain: SignedDecimal, min: SignedDecimal, max: SignedDecimal) -> CommonResult<Self> {
+        if min > max {
+            return Err(CommonError::Generic(format!(
+                "Controller bounds are reversed, min {} exceeds max {}",
+                min.to_string(),
+                max.to_string()
+            )));
+        }
+        Ok(Self { gain, min, max })
+    }
+
+    /// Control output for the signed error `target - observed`
+    pub fn output(&self, target: SignedDecimal, observed: SignedDecimal) -> SignedDecimal {
+        let error = target.saturating_sub(observed);
+        self.gain.saturating_mul(error).max(self.min).min(self.max)
+    }
+}
+
+/// PID controller, producing `kp * error + ki * integral + kd * derivative`
+/// of the signed error `target - observed`, clamped to `[min, max]`.
+///
+/// The gains live here and the accumulated terms in a separate [`PidState`],
+/// so both can be stored and the gains changed without losing the state.
+/// Like [`Proportional`], every step saturates rather than erroring.
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, JsonSchema)]
+pub struct Pid {
+    pub kp: SignedDecimal,
+    pub ki: SignedDecimal,
+    pub kd: SignedDecimal,
+    /// Largest magnitude of the integral, so a long excursion can't wind it
+    /// up and hold the output at a bound after the error changes sign
+    pub integral_limit: Decimal256,
+    /// Weight of the newest sample in the moving average of the derivative,
+    /// where one disables smoothing
+    pub derivative_weight: Decimal256,
+    pub min: SignedDecimal,
+    pub max: SignedDecimal,
+}
+
+/// Terms a [`Pid`] carries between updates, starting from the default
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, Default, PartialEq, JsonSchema)]
+pub struct PidState {
+    pub integral: SignedDecimal,
+    pub derivative: SignedDecimal,
+    pub last_error: Option<SignedDecimal>,
+}
+
+impl Pid {
+    /// Errors if `min` exceeds `max` or `derivative_weight` exceeds one
+    pub fn validate(&self) -> CommonResult<()> {
+        Proportional::new(self.kp, self.min, self.max)?;
+        if self.derivative_weight > Decimal256::one() {
+            return Err(CommonError::Generic(format!(
+                "Derivative weight {} exceeds one",
+                self.derivative_weight
+            )));
+        }
+        Ok(())
+    }
+
+    /// Advances `state` by `elapsed` seconds and returns the control output.
+    ///
+    /// With no time elapsed only the proportional term responds. The first
+    /// update has no previous error, so its derivative is zero.
+    pub fn update(
+        &self,
+        state: &mut PidState,
+        target: SignedDecimal,
+        observed: SignedDecimal,
+        elapsed: u64,
+    ) -> SignedDecimal {
+        let error = target.saturating_sub(observed);
+        if elapsed > 0 {
+            let dt = SignedDecimal::from(elapsed);
+            let limit = SignedDecimal::from(self.integral_limit);
+            state.integral = state
+                .integral
+                .saturating_add(error.saturating_mul(dt))
+                .max(-limit)
+                .min(limit);
+            if let Some(last_error) = state.last_error {
+                let weight = SignedDecimal::from(self.derivative_weight);
+                let sample = error.saturating_sub(last_error).saturating_div(dt);
+                state.derivative = weight.saturating_mul(sample).saturating_add(
+                    SignedDecimal::one()
+                        .saturating_sub(weight)
+                        .saturating_mul(state.derivative),
+                );
+            }
+            state.last_error = Some(error);
+        } else if state.last_error.is_none() {
+            state.last_error = Some(error);
+        }
+        self.kp
+            .saturating_mul(error)
+            .saturating_add(self.ki.saturating_mul(state.integral))
+            .saturating_add(self.kd.saturating_mul(state.derivative))
+            .max(self.min)
+            .min(self.max)
+    }
+}
+
+#[test]
+fn test_proportional() {
+    use std::str::FromStr;
+
+    let dec = |s: &str| SignedDecimal::from_str(s).unwrap();
+    let controller = Proportional::new(dec("0.5"), dec("-0.01"), dec("0.02")).unwrap();
+
+    // Below the peg pushes up, above it pushes down
+    assert_eq!(controller.output(dec("1"), dec("0.99")), dec("0.005"));
+    assert_eq!(controller.output(dec("1"), dec("1.004")), dec("-0.002"));
+    assert_eq!(controller.output(dec("1"), dec("1")), dec("0"));
+
+    // Clamped at either end, even where the product would overflow
+    assert_eq!(controller.output(dec("1"), dec("0.5")), dec("0.02"));
+    assert_eq!(controller.output(dec("1"), dec("3")), dec("-0.01"));
+    assert_eq!(
+        controller.output(SignedDecimal::MIN, SignedDecimal::MAX),
+        dec("-0.01")
+    );
+
+    // A negative gain reverses the response
+    let inverse = Proportional::new(dec("-2"), dec("-1"), dec("1")).unwrap();
+    assert_eq!(inverse.output(dec("1"), dec("0.9")), dec("-0.2"));
+
+    assert!(Proportional::new(dec("1"), dec("1"), dec("-1")).is_err());
+}
+
+#[test]
+fn test_pid() {
+    use std::str::FromStr;
+
+    let dec = |s: &str| SignedDecimal::from_str(s).unwrap();
+    let pid = Pid {
+        kp: dec("0.5"),
+        ki: dec("0.1"),
+        kd: dec("1"),
+        integral_limit: Decimal256::from_str("2").unwrap(),
+        derivative_weight: Decimal256::from_str("0.5").unwrap(),
+        min: dec("-1"),
+        max: dec("1"),
+    };
+    pid.validate().unwrap();
+    let mut state = PidState::default();
+
+    // 0.5 * 0.1 + 0.1 * (0.1 * 10), with no derivative yet
+    assert_eq!(
+        pid.update(&mut state, dec("1"), dec("0.9"), 10),
+        dec("0.15")
+    );
+    // The integral cancels out and the derivative is half of -0.02
+    assert_eq!(
+        pid.update(&mut state, dec("1"), dec("1.1"), 10),
+        dec("-0.06")
+    );
+    assert_eq!(state.integral, dec("0"));
+    assert_eq!(state.derivative, dec("-0.01"));
+
+    // A long excursion stops winding up at the limit, so the output follows
+    // the error as soon as it flips sign
+    assert_eq!(
+        pid.update(&mut state, dec("1"), dec("0"), 100),
+        dec("0.7005")
+    );
+    assert_eq!(state.integral, dec("2"));
+    assert_eq!(
+        pid.update(&mut state, dec("1"), dec("2"), 10),
+        dec("-0.79975")
+    );
+    assert_eq!(state.integral, dec("-2"));
+
+    // No time elapsed leaves the state alone
+    let before = state;
+    assert_eq!(
+        pid.update(&mut state, dec("1"), dec("1"), 0),
+        dec("-0.29975")
+    );
+    assert_eq!(state, before);
+
+    // The output is clamped, even where the terms overflow
+    let mut fresh = PidState::default();
+    assert_eq!(
+        pid.update(&mut fresh, SignedDecimal::MAX, SignedDecimal::MIN, 1),
+        dec("1")
+    );
+
+    let stored = serde_json::to_string(&state).unwrap();
+    assert_eq!(serde_json::from_str::<PidState>(&stored).unwrap(), state);
+
+    assert!(Pid {
+        derivative_weight: Decimal256::from_str("1.5").unwrap(),
+        ..pid
+    }
+    .validate()
+    .is_err());
+    assert!(Pid {
+        min: dec("2"),
+        ..pid
+    }
+    .validate()
+    .is_err());
+}
@@ -0,0 +1,159 @@
+//! Conversions with cosmwasm-std's `Int64`, `Int128` and `Int256`, which
+//! first appeared in cosmwasm-std 1.3. The rest of the crate still supports 1.0,
+//! so these are behind the `cosmwasm_1_3` feature.
+
+use std::convert::TryFrom;
+
+use cosmwasm_std::{ConversionOverflowError, Int128, Int256, Int64, Uint256};
+
+use crate::{
+    error::{CommonError, CommonResult},
+    ops::{saturate, SaturatingInto},
+    signed_decimal::SignedDecimal,
+    signed_int::SignedInt,
+};
+
+impl From<Int64> for SignedInt {
+    fn from(value: Int64) -> Self {
+        SignedInt::from(value.i64())
+    }
+}
+
+impl From<Int128> for SignedInt {
+    fn from(value: Int128) -> Self {
+        SignedInt::from(value.i128())
+    }
+}
+
+impl TryFrom<SignedInt> for Int64 {
+    type Error = CommonError;
+
+    fn try_from(value: SignedInt) -> CommonResult<Self> {
+        Ok(Int64::new(i64::try_from(value)?))
+    }
+}
+
+impl TryFrom<SignedInt> for Int128 {
+    type Error = CommonError;
+
+    fn try_from(value: SignedInt) -> CommonResult<Self> {
+        Ok(Int128::new(i128::try_from(value)?))
+    }
+}
+
+impl From<Int256> for SignedInt {
+    fn from(value: Int256) -> Self {
+        SignedInt::from_twos_complement(Uint256::from_be_bytes(value.to_be_bytes()))
+    }
+}
+
+/// Fails for NaN and values outside `[-2^255, 2^255 - 1]`
+impl TryFrom<SignedInt> for Int256 {
+    type Error = CommonError;
+
+    fn try_from(value: SignedInt) -> CommonResult<Self> {
+        let bits = value.to_twos_complement();
+        if value.is_nan() || SignedInt::from_twos_complement(bits) != value {
+            return Err(
+                ConversionOverflowError::new("SignedInt", "Int256", value.to_string()).into(),
+            );
+        }
+        Ok(Int256::from_be_bytes(bits.to_be_bytes()))
+    }
+}
+
+impl SaturatingInto<Int64> for SignedInt {
+    fn saturating_into(self) -> Int64 {
+        saturate(self, Int64::try_from(self), Int64::MIN, Int64::MAX)
+    }
+}
+
+impl SaturatingInto<Int128> for SignedInt {
+    fn saturating_into(self) -> Int128 {
+        saturate(self, Int128::try_from(self), Int128::MIN, Int128::MAX)
+    }
+}
+
+impl SaturatingInto<Int256> for SignedInt {
+    fn saturating_into(self) -> Int256 {
+        saturate(self, Int256::try_from(self), Int256::MIN, Int256::MAX)
+    }
+}
+
+impl From<Int64> for SignedDecimal {
+    fn from(value: Int64) -> Self {
+        SignedDecimal::from_int(value.i64())
+    }
+}
+
+impl From<Int128> for SignedDecimal {
+    fn from(value: Int128) -> Self {
+        SignedDecimal::from_int(value.i128())
+    }
+}
+
+/// Converts the integer part, truncating toward zero
+impl TryFrom<SignedDecimal> for Int64 {
+    type Error = CommonError;
+
+    fn try_from(value: SignedDecimal) -> CommonResult<Self> {
+        Int64::try_from(value.to_signed_int_trunc())
+    }
+}
+
+/// Converts the integer part, truncating toward zero
+impl TryFrom<SignedDecimal> for Int128 {
+    type Error = CommonError;
+
+    fn try_from(value: SignedDecimal) -> CommonResult<Self> {
+        Int128::try_from(value.to_signed_int_trunc())
+    }
+}
+
+#[test]
+fn test_cosmwasm_ints() {
+    use std::str::FromStr;
+
+    let int = |s: &str| SignedInt::from_str(s).unwrap();
+    let dec = |s: &str| SignedDecimal::from_str(s).unwrap();
+
+    assert_eq!(SignedInt::from(Int64::new(-42)), int("-42"));
+    assert_eq!(SignedInt::from(Int128::MIN), int(&i128::MIN.to_string()));
+    assert_eq!(Int64::try_from(int("-42")).unwrap(), Int64::new(-42));
+    assert_eq!(
+        Int64::try_from(int(&i64::MIN.to_string())).unwrap(),
+        Int64::MIN
+    );
+    assert!(Int64::try_from(int(&(i64::MAX as i128 + 1).to_string())).is_err());
+    assert_eq!(
+        Int128::try_from(int(&i128::MIN.to_string())).unwrap(),
+        Int128::MIN
+    );
+    assert!(Int128::try_from(int(&u128::MAX.to_string())).is_err());
+    assert!(Int128::try_from(SignedInt::nan()).is_err());
+
+    assert_eq!(SignedDecimal::from(Int64::new(-7)), dec("-7"));
+    assert_eq!(Int64::try_from(dec("-7.9")).unwrap(), Int64::new(-7));
+    assert_eq!(Int128::try_from(dec("-0.5")).unwrap(), Int128::zero());
+    assert!(Int64::try_from(SignedDecimal::MAX).is_err());
+
+    let max = SignedInt::from_twos_complement(Uint256::MAX >> 1);
+    let min = (-max).checked_sub(SignedInt::from(1u8)).unwrap();
+    assert_eq!(SignedInt::from(Int256::from(-42i128)), int("-42"));
+    assert_eq!(SignedInt::from(Int256::MIN), min);
+    assert_eq!(SignedInt::from(Int256::MAX), max);
+    assert_eq!(Int256::try_from(int("-42")).unwrap(), Int256::from(-42i128));
+    assert_eq!(Int256::try_from(min).unwrap(), Int256::MIN);
+    assert_eq!(Int256::try_from(max).unwrap(), Int256::MAX);
+    assert!(Int256::try_from(max.checked_add(SignedInt::from(1u8)).unwrap()).is_err());
+    assert!(Int256::try_from(min.checked_sub(SignedInt::from(1u8)).unwrap()).is_err());
+    assert!(Int256::try_from(SignedInt::MIN).is_err());
+    assert!(Int256::try_from(SignedInt::nan()).is_err());
+
+    let saturated: Int256 = SignedInt::MIN.saturating_into();
+    assert_eq!(saturated, Int256::MIN);
+    let saturated: Int128 = SignedInt::MAX.saturating_into();
+    assert_eq!(saturated, Int128::MAX);
+    let saturated: Int64 = int("-42").saturating_into();
+    assert_eq!(saturated, Int64::new(-42));
+}
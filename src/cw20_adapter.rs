@@ -0,0 +1,126 @@
+use cosmwasm_std::{Addr, CosmosMsg, Uint128};
+use cw20::{BalanceResponse, Cw20Contract, Cw20ExecuteMsg};
+
+use crate::{
+    error::{CommonError, CommonResult},
+    signed_int::SignedInt,
+};
+
+/// How a signed cw20 balance adjustment is realized
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Cw20Mode {
+    /// Credits are paid out of the contract's own holdings and debits are
+    /// pulled back with `TransferFrom`, which needs an allowance from the account
+    Transfer,
+    /// Credits are minted and debits burned with `BurnFrom`, which needs the
+    /// contract to be minter and hold an allowance from the account
+    MintBurn,
+}
+
+/// Builds the message moving `account`'s balance of `token` by `delta`.
+///
+/// `balance` is the account's current balance as returned by the token's
+/// `Balance` query; a debit larger than it is rejected here rather than
+/// failing later inside the sub-message. Returns `None` for a zero delta.
+pub fn adjustment_msg(
+    token: &Addr,
+    contract: &Addr,
+    account: &Addr,
+    balance: &BalanceResponse,
+    delta: SignedInt,
+    mode: Cw20Mode,
+) -> CommonResult<Option<CosmosMsg>> {
+    if delta.is_zero() {
+        return Ok(None);
+    }
+    let amount = Uint128::try_from(delta.value)?;
+    let msg = match (delta.is_positive, mode) {
+        (true, Cw20Mode::Transfer) => Cw20ExecuteMsg::Transfer {
+            recipient: account.to_string(),
+            amount,
+        },
+        (true, Cw20Mode::MintBurn) => Cw20ExecuteMsg::Mint {
+            recipient: account.to_string(),
+            amount,
+        },
+        (false, mode) => {
+            if amount > balance.balance {
+                return Err(CommonError::Generic(format!(
+                    "Cannot debit {} from {} holding {}",
+                    amount, account, balance.balance
+                )));
+            }
+            match mode {
+                Cw20Mode::Transfer => Cw20ExecuteMsg::TransferFrom {
+                    owner: account.to_string(),
+                    recipient: contract.to_string(),
+                    amount,
+                },
+                Cw20Mode::MintBurn => Cw20ExecuteMsg::BurnFrom {
+                    owner: account.to_string(),
+                    amount,
+                },
+            }
+        }
+    };
+    Ok(Some(Cw20Contract(token.clone()).call(msg)?))
+}
+
+#[test]
+fn test_adjustment_msg() {
+    use std::str::FromStr;
+
+    let token = Addr::unchecked("token");
+    let contract = Addr::unchecked("contract");
+    let account = Addr::unchecked("account");
+    let balance = BalanceResponse {
+        balance: Uint128::new(100),
+    };
+    let adjust = |delta: &str, mode| {
+        adjustment_msg(
+            &token,
+            &contract,
+            &account,
+            &balance,
+            SignedInt::from_str(delta).unwrap(),
+            mode,
+        )
+    };
+    let expected = |msg| Some(Cw20Contract(token.clone()).call(msg).unwrap());
+
+    assert_eq!(adjust("0", Cw20Mode::Transfer).unwrap(), None);
+    assert_eq!(
+        adjust("40", Cw20Mode::Transfer).unwrap(),
+        expected(Cw20ExecuteMsg::Transfer {
+            recipient: "account".into(),
+            amount: Uint128::new(40),
+        })
+    );
+    assert_eq!(
+        adjust("40", Cw20Mode::MintBurn).unwrap(),
+        expected(Cw20ExecuteMsg::Mint {
+            recipient: "account".into(),
+            amount: Uint128::new(40),
+        })
+    );
+    assert_eq!(
+        adjust("-100", Cw20Mode::Transfer).unwrap(),
+        expected(Cw20ExecuteMsg::TransferFrom {
+            owner: "account".into(),
+            recipient: "contract".into(),
+            amount: Uint128::new(100),
+        })
+    );
+    assert_eq!(
+        adjust("-100", Cw20Mode::MintBurn).unwrap(),
+        expected(Cw20ExecuteMsg::BurnFrom {
+            owner: "account".into(),
+            amount: Uint128::new(100),
+        })
+    );
+    assert!(adjust("-101", Cw20Mode::MintBurn).is_err());
+    assert!(matches!(
+        adjust(&SignedInt::MAX.to_string(), Cw20Mode::Transfer),
+        Err(CommonError::ConversionOverflow(_))
+    ));
+}
@@ -0,0 +1,81 @@
+//! Differential tests running the same random operations through this
+//! crate's `SignedInt` and cosmwasm-std's `Int256`.
+//!
+//! Results must match exactly wherever the upstream type can represent
+//! them, and where upstream fails ours must either fail too or land outside
+//! the upstream range, which is narrower by one bit. Inputs come from a
+//! seeded generator so a failure names a reproducible case.
+
+use std::{convert::TryFrom, fmt::Debug, str::FromStr};
+
+use cosmwasm_std::{Int256, Uint256};
+
+use crate::{error::CommonResult, signed_int::SignedInt};
+
+const CASES: u64 = 20_000;
+
+/// splitmix64, which is plenty for spreading inputs around
+struct Rng(u64);
+
+impl Rng {
+    fn next(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+
+    /// An Int256 with a random number of significant bytes, so small values
+    /// and values near the bounds all come up
+    fn int256(&mut self) -> Int256 {
+        let len = (self.next() % 33) as usize;
+        let mut bytes = [0u8; 32];
+        for b in bytes[32 - len..].iter_mut() {
+            *b = self.next() as u8;
+        }
+        if len == 32 {
+            // Any 32 bytes are a valid two's complement value of either sign
+            return Int256::from_be_bytes(bytes);
+        }
+        let value = SignedInt {
+            value: Uint256::from_be_bytes(bytes),
+            is_positive: self.next() % 2 == 0,
+        };
+        Int256::try_from(value).unwrap()
+    }
+}
+
+/// Checks our result against upstream's, converting ours to the upstream
+/// type so out of range results count as failures
+fn agree<T, U>(op: &str, ours: CommonResult<T>, upstream: Option<U>)
+where
+    U: TryFrom<T> + PartialEq + Debug,
+{
+    let ours = ours.ok().and_then(|v| U::try_from(v).ok());
+    assert_eq!(ours, upstream, "{op}");
+}
+
+#[test]
+fn test_differential_signed_int() {
+    let mut rng = Rng(0x05ee_d1e7);
+    for case in 0..CASES {
+        let (a, b) = (rng.int256(), rng.int256());
+        let (x, y) = (SignedInt::from(a), SignedInt::from(b));
+        let op = |name: &str| format!("case {case}: {a} {name} {b}");
+
+        assert_eq!(x.to_string(), a.to_string(), "case {case}");
+        assert_eq!(SignedInt::from_str(&a.to_string()).unwrap(), x);
+        assert_eq!(x.cmp(&y), a.cmp(&b), "{}", op("cmp"));
+
+        agree(&op("+"), x.checked_add(y), a.checked_add(b).ok());
+        agree(&op("-"), x.checked_sub(y), a.checked_sub(b).ok());
+        agree(&op("*"), x.checked_mul(y), a.checked_mul(b).ok());
+        agree(&op("/"), x.checked_div(y), a.checked_div(b).ok());
+        agree(&op("%"), x.checked_rem(y), a.checked_rem(b).ok());
+        if a != Int256::MIN {
+            agree(&op("neg"), x.checked_neg(), Some(-a));
+            agree(&op("abs"), x.checked_abs(), Some(a.abs()));
+        }
+    }
+}
@@ -1,4 +1,7 @@
-use cosmwasm_std::{Decimal256RangeExceeded, StdError};
+use cosmwasm_std::{
+    Coin, ConversionOverflowError, Decimal256RangeExceeded, DivideByZeroError, OverflowError,
+    StdError,
+};
 use thiserror::Error;
 
 pub type CommonResult<T> = core::result::Result<T, CommonError>;
@@ -13,4 +16,271 @@ pub enum CommonError {
 
     #[error("{0}")]
     Decimal256RangeExceeded(#[from] Decimal256RangeExceeded),
+
+    #[error("{0}")]
+    Overflow(#[source] OverflowError),
+
+    #[error("{0}")]
+    DivideByZero(#[source] DivideByZeroError),
+
+    #[error("{0}")]
+    ConversionOverflow(#[from] ConversionOverflowError),
+
+    #[error("{value} with {decimal_places} decimal places exceeds the SignedDecimal range")]
+    ScaledValueOutOfRange { value: String, decimal_places: u32 },
+
+    #[error("{value} has digits beyond {decimal_places} decimal places")]
+    PrecisionLoss { value: String, decimal_places: u32 },
+
+    #[error("{value} is outside the allowed range [{min}, {max}]")]
+    OutOfRange {
+        value: String,
+        min: String,
+        max: String,
+    },
+
+    #[error("{0}")]
+    Parse(#[from] ParseSignedError),
+
+    #[error("Element {index}: {source}")]
+    ParseAt {
+        index: usize,
+        source: ParseSignedError,
+    },
+
+    #[error("{value} must be {expected}")]
+    WrongSign { value: String, expected: String },
+
+    #[error("Net flow {net} exceeds the cap of {cap}")]
+    FlowLimitExceeded { net: String, cap: String },
+
+    #[error("Step {step} of the settlement failed: {reason}")]
+    SettlementStep { step: usize, reason: String },
+
+    #[error("{address} must deposit {amount}")]
+    RequiredDeposit { address: String, amount: Coin },
+}
+
+// Every checked operation that fails reports through one of these two, so
+// with the `trace` feature they are where simulations see formulas blow up
+
+impl From<OverflowError> for CommonError {
+    fn from(err: OverflowError) -> Self {
+        #[cfg(feature = "trace")]
+        tracing::debug!(
+            operation = %err.operation,
+            lhs = %err.operand1,
+            rhs = %err.operand2,
+            "checked operation overflowed"
+        );
+        CommonError::Overflow(err)
+    }
+}
+
+impl From<DivideByZeroError> for CommonError {
+    fn from(err: DivideByZeroError) -> Self {
+        #[cfg(feature = "trace")]
+        tracing::debug!(lhs = %err.operand, "checked operation divided by zero");
+        CommonError::DivideByZero(err)
+    }
+}
+
+/// Longest string the FromStr impls will look at. The longest canonical value
+/// is 79 characters, so this leaves room for leading zeros while refusing
+/// adversarial inputs of many kilobytes before any digit is parsed.
+pub const MAX_PARSE_LEN: usize = 128;
+
+/// Why a string isn't a valid SignedInt or SignedDecimal, with positions
+/// counted in characters from the start of the input
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+pub enum ParseSignedError {
+    #[error("No digits to parse, expected a number such as \"-1.5\"")]
+    EmptyInput,
+
+    #[error("Invalid character at position {pos}, expected digits after an optional '-'")]
+    InvalidCharacter { pos: usize },
+
+    #[error("Too many fractional digits: got {got}, at most {max} are supported")]
+    TooManyFractionalDigits { got: usize, max: u32 },
+
+    #[error("Magnitude too large for the type")]
+    MagnitudeTooLarge,
+
+    #[error("Input of {len} bytes exceeds the limit of {max}")]
+    InputTooLong { len: usize, max: usize },
+
+    #[error("Radix {radix} is not supported")]
+    InvalidRadix { radix: u32 },
+}
+
+impl ParseSignedError {
+    /// Splits `s` into its sign and unsigned digits, checking the syntax of a
+    /// number with at most `max_fraction_digits` digits after the point
+    pub(crate) fn split_sign(
+        s: &str,
+        max_fraction_digits: u32,
+    ) -> Result<(bool, &str), ParseSignedError> {
+        if s.len() > MAX_PARSE_LEN {
+            return Err(ParseSignedError::InputTooLong {
+                len: s.len(),
+                max: MAX_PARSE_LEN,
+            });
+        }
+        let (is_positive, digits, offset) = match s.strip_prefix('-') {
+            Some(rest) => (false, rest, 1),
+            None => (true, s, 0),
+        };
+        if digits.is_empty() {
+            return Err(ParseSignedError::EmptyInput);
+        }
+        let mut point = None;
+        for (i, c) in digits.chars().enumerate() {
+            let valid = match c {
+                '0'..='9' => true,
+                '.' => max_fraction_digits > 0 && point.is_none() && i > 0,
+                _ => false,
+            };
+            if !valid {
+                return Err(ParseSignedError::InvalidCharacter { pos: offset + i });
+            }
+            if c == '.' {
+                point = Some(i);
+            }
+        }
+        if let Some(i) = point {
+            let got = digits.len() - i - 1;
+            if got == 0 {
+                return Err(ParseSignedError::InvalidCharacter { pos: offset + i });
+            }
+            if got > max_fraction_digits as usize {
+                return Err(ParseSignedError::TooManyFractionalDigits {
+                    got,
+                    max: max_fraction_digits,
+                });
+            }
+        }
+        Ok((is_positive, digits))
+    }
+}
+
+#[test]
+fn test_parse_signed_error() {
+    use std::str::FromStr;
+
+    use crate::{signed_decimal::SignedDecimal, signed_int::SignedInt};
+
+    let dec = |s: &str| SignedDecimal::from_str(s).unwrap_err();
+    assert_eq!(dec(""), ParseSignedError::EmptyInput);
+    assert_eq!(dec("-"), ParseSignedError::EmptyInput);
+    assert_eq!(dec("-1.5x"), ParseSignedError::InvalidCharacter { pos: 4 });
+    assert_eq!(dec(" 1"), ParseSignedError::InvalidCharacter { pos: 0 });
+    assert_eq!(dec("1.2.3"), ParseSignedError::InvalidCharacter { pos: 3 });
+    assert_eq!(dec("--1"), ParseSignedError::InvalidCharacter { pos: 1 });
+    assert_eq!(dec(".5"), ParseSignedError::InvalidCharacter { pos: 0 });
+    assert_eq!(dec("-5."), ParseSignedError::InvalidCharacter { pos: 2 });
+    assert_eq!(
+        dec("0.0000000000000000001"),
+        ParseSignedError::TooManyFractionalDigits { got: 19, max: 18 }
+    );
+    assert_eq!(
+        dec("115792089237316195423570985008687907853269984665640564039458"),
+        ParseSignedError::MagnitudeTooLarge
+    );
+    assert_eq!(
+        dec(&"1".repeat(10_000)),
+        ParseSignedError::InputTooLong {
+            len: 10_000,
+            max: MAX_PARSE_LEN
+        }
+    );
+    // Padding is fine up to the limit
+    let padded = format!("-{}1.5", "0".repeat(MAX_PARSE_LEN - 4));
+    assert_eq!(
+        SignedDecimal::from_str(&padded).unwrap(),
+        SignedDecimal::from_str("-1.5").unwrap()
+    );
+
+    let int = |s: &str| SignedInt::from_str(s).unwrap_err();
+    assert_eq!(int("1.5"), ParseSignedError::InvalidCharacter { pos: 1 });
+    assert_eq!(
+        int("115792089237316195423570985008687907853269984665640564039457584007913129639936"),
+        ParseSignedError::MagnitudeTooLarge
+    );
+    assert_eq!(
+        CommonError::from(int("+1")).to_string(),
+        "Invalid character at position 0, expected digits after an optional '-'"
+    );
+}
+
+#[test]
+#[cfg(feature = "trace")]
+fn test_trace_events() {
+    use std::{
+        fmt::Debug,
+        str::FromStr,
+        sync::{Arc, Mutex},
+    };
+
+    use tracing::{
+        field::{Field, Visit},
+        span, Event, Metadata, Subscriber,
+    };
+
+    use crate::{signed_decimal::SignedDecimal, signed_int::SignedInt};
+
+    /// Collects each event's fields as "name=value" strings
+    #[derive(Clone, Default)]
+    struct Collector(Arc<Mutex<Vec<String>>>);
+
+    impl Visit for Collector {
+        fn record_debug(&mut self, field: &Field, value: &dyn Debug) {
+            let mut events = self.0.lock().unwrap();
+            let event = events.last_mut().unwrap();
+            event.push_str(&format!(" {}={:?}", field.name(), value));
+        }
+    }
+
+    impl Subscriber for Collector {
+        fn enabled(&self, _: &Metadata<'_>) -> bool {
+            true
+        }
+        fn new_span(&self, _: &span::Attributes<'_>) -> span::Id {
+            span::Id::from_u64(1)
+        }
+        fn record(&self, _: &span::Id, _: &span::Record<'_>) {}
+        fn record_follows_from(&self, _: &span::Id, _: &span::Id) {}
+        fn event(&self, event: &Event<'_>) {
+            self.0.lock().unwrap().push(String::new());
+            event.record(&mut self.clone());
+        }
+        fn enter(&self, _: &span::Id) {}
+        fn exit(&self, _: &span::Id) {}
+    }
+
+    let collector = Collector::default();
+    tracing::subscriber::with_default(collector.clone(), || {
+        let dec = |s: &str| SignedDecimal::from_str(s).unwrap();
+        let int = SignedInt::from_u128;
+        assert!(SignedDecimal::MAX.checked_add(dec("1")).is_err());
+        assert!(dec("-1.5").checked_div(dec("0")).is_err());
+        assert!(int(7).checked_mul(int(3)).is_ok());
+        assert!(SignedDecimal::MIN.checked_sub(dec("1")).is_err());
+        assert!(SignedInt::MIN.checked_sub(int(1)).is_err());
+        assert!(SignedInt::MAX.checked_mul(int(2)).is_err());
+        assert!(int(7).checked_div(int(0)).is_err());
+        assert!(SignedInt::MAX.overflowing_add(int(1)).1);
+    });
+    // Exactly one event per failed operation
+    let events = collector.0.lock().unwrap();
+    assert_eq!(events.len(), 7, "{events:?}");
+    assert!(events[0].contains("operation=Add"));
+    assert!(events[0].contains("rhs=1"));
+    assert!(events[0].contains("checked operation overflowed"));
+    assert!(events[1].contains("lhs=-1.5"));
+    assert!(events[1].contains("divided by zero"));
+    assert!(events[2].contains("operation=Sub"));
+    assert!(events[3].contains("operation=Sub"));
+    assert!(events[4].contains("operation=Mul"));
+    assert!(events[5].contains("lhs=7"));
+    assert!(events[6].contains("operation=Add"));
 }
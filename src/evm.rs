@@ -0,0 +1,119 @@
+//! Conversions with the 256 bit EVM integers of ethers-rs and alloy, for
+//! keepers reading EVM state into CosmWasm math without string round trips.
+//!
+//! `I256` is two's complement, so as with cosmwasm's `Int256` it covers
+//! `[-2^255, 2^255 - 1]`. Their `U256` types come from primitive-types and
+//! ruint, which [`crate::u256`] covers.
+
+use cosmwasm_std::{ConversionOverflowError, Uint256};
+
+use crate::{error::CommonResult, signed_int::SignedInt};
+
+/// Two's complement bits of a value in the `I256` range
+fn i256_bits(value: SignedInt) -> CommonResult<Uint256> {
+    let bits = value.to_twos_complement();
+    if value.is_nan() || SignedInt::from_twos_complement(bits) != value {
+        return Err(ConversionOverflowError::new("SignedInt", "I256", value.to_string()).into());
+    }
+    Ok(bits)
+}
+
+/// Implements the `I256` conversions for one library, given the module of
+/// [`crate::u256`] handling its `U256`
+macro_rules! impl_i256_conversions {
+    ($lib:ident, $($path:ident)::+, $u256:ident) => {
+        mod $lib {
+            use std::convert::TryFrom;
+
+            use $($path)::+::I256;
+
+            use super::i256_bits;
+            use crate::{
+                error::{CommonError, CommonResult},
+                signed_int::SignedInt,
+                u256::$u256::{from_uint256, to_uint256},
+            };
+
+            impl From<I256> for SignedInt {
+                fn from(value: I256) -> Self {
+                    SignedInt::from_twos_complement(to_uint256(value.into_raw()))
+                }
+            }
+
+            /// Fails for NaN and values outside `[-2^255, 2^255 - 1]`
+            impl TryFrom<SignedInt> for I256 {
+                type Error = CommonError;
+
+                fn try_from(value: SignedInt) -> CommonResult<Self> {
+                    Ok(I256::from_raw(from_uint256(i256_bits(value)?)))
+                }
+            }
+        }
+    };
+}
+
+#[cfg(feature = "ethers")]
+impl_i256_conversions!(ethers, ethers_core::types, primitive_types);
+
+#[cfg(feature = "alloy")]
+impl_i256_conversions!(alloy, alloy_primitives, ruint);
+
+#[test]
+fn test_i256_bits() {
+    use std::str::FromStr;
+
+    let int = |s: &str| SignedInt::from_str(s).unwrap();
+    let max = SignedInt::from_twos_complement(Uint256::MAX >> 1);
+    let min = (-max).checked_sub(int("1")).unwrap();
+
+    for x in [int("0"), int("-1"), int("42"), min, max] {
+        assert_eq!(SignedInt::from_twos_complement(i256_bits(x).unwrap()), x);
+    }
+    assert_eq!(i256_bits(int("-1")).unwrap(), Uint256::MAX);
+    assert!(i256_bits(max.checked_add(int("1")).unwrap()).is_err());
+    assert!(i256_bits(min.checked_sub(int("1")).unwrap()).is_err());
+    assert!(i256_bits(SignedInt::nan()).is_err());
+}
+
+#[test]
+#[cfg(feature = "alloy")]
+fn test_alloy_conversions() {
+    use std::{convert::TryFrom, str::FromStr};
+
+    use alloy_primitives::{I256, U256};
+
+    let int = |s: &str| SignedInt::from_str(s).unwrap();
+    assert_eq!(SignedInt::from(I256::try_from(-42i64).unwrap()), int("-42"));
+    assert_eq!(
+        SignedInt::from(I256::MIN),
+        int("-57896044618658097711785492504343953926634992332820282019728792003956564819968")
+    );
+    assert_eq!(
+        I256::try_from(int("-42")).unwrap(),
+        I256::try_from(-42i64).unwrap()
+    );
+    assert_eq!(SignedInt::from(U256::MAX), SignedInt::MAX);
+    assert_eq!(U256::try_from(int("7")).unwrap(), U256::from(7u8));
+    assert!(U256::try_from(int("-7")).is_err());
+    assert!(I256::try_from(SignedInt::MAX).is_err());
+}
+
+#[test]
+#[cfg(feature = "ethers")]
+fn test_ethers_conversions() {
+    use std::{convert::TryFrom, str::FromStr};
+
+    use ethers_core::types::{I256, U256};
+
+    let int = |s: &str| SignedInt::from_str(s).unwrap();
+    assert_eq!(SignedInt::from(I256::from(-42i64)), int("-42"));
+    assert_eq!(
+        SignedInt::from(I256::MIN),
+        int("-57896044618658097711785492504343953926634992332820282019728792003956564819968")
+    );
+    assert_eq!(I256::try_from(int("-42")).unwrap(), I256::from(-42i64));
+    assert_eq!(SignedInt::from(U256::MAX), SignedInt::MAX);
+    assert_eq!(U256::try_from(int("7")).unwrap(), U256::from(7u8));
+    assert!(U256::try_from(int("-7")).is_err());
+    assert!(I256::try_from(SignedInt::MAX).is_err());
+}
@@ -0,0 +1,94 @@
+use cosmwasm_std::Uint256;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    error::{CommonError, CommonResult},
+    signed_int::SignedInt,
+};
+
+/// Circuit breaker on the net signed flow through a contract over the last
+/// `window` blocks, e.g. bridge inflows minus outflows.
+///
+/// Flows are kept per block height and pruned as they leave the window, so
+/// the stored state is bounded by the number of active blocks in a window.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct SignedFlowLimiter {
+    pub window: u64,
+    pub cap: Uint256,
+    flows: Vec<(u64, SignedInt)>,
+}
+
+impl SignedFlowLimiter {
+    pub fn new(window: u64, cap: Uint256) -> Self {
+        Self {
+            window,
+            cap,
+            flows: vec![],
+        }
+    }
+
+    /// Net flow over the blocks in `(height - window, height]`
+    pub fn net_flow(&self, height: u64) -> SignedInt {
+        self.flows
+            .iter()
+            .filter(|(h, _)| self.in_window(*h, height))
+            .fold(SignedInt::zero(), |net, (_, flow)| {
+                net.saturating_add(*flow)
+            })
+    }
+
+    /// Records `flow` at `height` and returns the new net flow. Errors without
+    /// recording anything if the net magnitude would exceed the cap.
+    pub fn record(&mut self, height: u64, flow: SignedInt) -> CommonResult<SignedInt> {
+        self.flows
+            .retain(|(h, _)| h.saturating_add(self.window) > height);
+        let net = self.net_flow(height).checked_add(flow)?;
+        if net.value > self.cap {
+            return Err(CommonError::FlowLimitExceeded {
+                net: net.to_string(),
+                cap: self.cap.to_string(),
+            });
+        }
+        match self.flows.last_mut() {
+            Some((h, total)) if *h == height => *total = total.checked_add(flow)?,
+            _ => self.flows.push((height, flow)),
+        }
+        Ok(net)
+    }
+
+    fn in_window(&self, recorded: u64, height: u64) -> bool {
+        recorded <= height && recorded.saturating_add(self.window) > height
+    }
+}
+
+#[test]
+fn test_flow_limiter() {
+    use std::str::FromStr;
+
+    let int = |s: &str| SignedInt::from_str(s).unwrap();
+    let mut limiter = SignedFlowLimiter::new(10, Uint256::from_u128(100));
+
+    assert_eq!(limiter.record(1, int("60")).unwrap(), int("60"));
+    assert_eq!(limiter.record(1, int("30")).unwrap(), int("90"));
+    assert_eq!(
+        limiter.record(5, int("20")),
+        Err(CommonError::FlowLimitExceeded {
+            net: "110".into(),
+            cap: "100".into(),
+        })
+    );
+    // Outflows net against inflows
+    assert_eq!(limiter.record(5, int("-150")).unwrap(), int("-60"));
+    assert!(limiter.record(6, int("-41")).is_err());
+
+    // Height 1 leaves the window at height 11
+    assert_eq!(limiter.net_flow(10), int("-60"));
+    assert_eq!(limiter.net_flow(11), int("-150"));
+    assert_eq!(limiter.record(15, int("-10")).unwrap(), int("-10"));
+    assert_eq!(limiter.net_flow(15), int("-10"));
+
+    let state = serde_json::to_string(&limiter).unwrap();
+    let restored: SignedFlowLimiter = serde_json::from_str(&state).unwrap();
+    assert_eq!(restored, limiter);
+}
@@ -0,0 +1,59 @@
+use std::str::FromStr;
+
+use async_graphql::{InputType, InputValueError, InputValueResult, Scalar, ScalarType, Value};
+
+use crate::{signed_decimal::SignedDecimal, signed_int::SignedInt};
+
+/// Parses a scalar from its string form, as written by `to_value`. Numbers
+/// are rejected since JSON clients can't carry these ranges losslessly.
+fn parse_string<T>(value: Value) -> InputValueResult<T>
+where
+    T: InputType + FromStr,
+    T::Err: std::fmt::Display,
+{
+    match &value {
+        Value::String(s) => T::from_str(s).map_err(InputValueError::custom),
+        _ => Err(InputValueError::expected_type(value)),
+    }
+}
+
+/// A signed decimal with 18 decimal places, as a string such as "-1.5"
+#[Scalar(name = "SignedDecimal")]
+impl ScalarType for SignedDecimal {
+    fn parse(value: Value) -> InputValueResult<Self> {
+        parse_string(value)
+    }
+
+    fn to_value(&self) -> Value {
+        Value::String(self.to_string())
+    }
+}
+
+/// A signed 256-bit integer, as a string such as "-15"
+#[Scalar(name = "SignedInt")]
+impl ScalarType for SignedInt {
+    fn parse(value: Value) -> InputValueResult<Self> {
+        parse_string(value)
+    }
+
+    fn to_value(&self) -> Value {
+        Value::String(self.to_string())
+    }
+}
+
+#[test]
+fn test_graphql_scalars() {
+    let string = |s: &str| Value::String(s.to_string());
+
+    let x = <SignedDecimal as ScalarType>::parse(string("-1.5")).unwrap();
+    assert_eq!(x, SignedDecimal::from_str("-1.5").unwrap());
+    assert_eq!(ScalarType::to_value(&x), string("-1.5"));
+    assert!(<SignedDecimal as ScalarType>::parse(string("1.5.5")).is_err());
+    assert!(<SignedDecimal as ScalarType>::parse(Value::from(1)).is_err());
+
+    let n = <SignedInt as ScalarType>::parse(string("-15")).unwrap();
+    assert_eq!(n, SignedInt::from_str("-15").unwrap());
+    assert_eq!(ScalarType::to_value(&n), string("-15"));
+    assert!(<SignedInt as ScalarType>::parse(string("0.5")).is_err());
+    assert!(<SignedInt as ScalarType>::parse(Value::Null).is_err());
+}
@@ -0,0 +1,145 @@
+use cosmwasm_std::Uint256;
+
+use crate::{
+    error::{CommonError, CommonResult},
+    signed_int::SignedInt,
+};
+
+/// Applies a sequence of signed deltas to a balance as one transaction.
+///
+/// The running value may dip below zero part way through, e.g. a debit
+/// settled before the matching credit, and only the final value has to be
+/// non-negative. Nothing is written back until [`NonNegativeGuard::commit`],
+/// which names the step that broke the settlement when it fails.
+#[derive(Clone, Debug, PartialEq)]
+pub struct NonNegativeGuard {
+    running: SignedInt,
+    steps: usize,
+    /// First step of the final run of negative values
+    negative_since: Option<usize>,
+    /// Step that could not be applied at all, and why
+    failed: Option<(usize, String)>,
+}
+
+impl NonNegativeGuard {
+    pub fn new(balance: Uint256) -> Self {
+        Self {
+            running: SignedInt::from(balance),
+            steps: 0,
+            negative_since: None,
+            failed: None,
+        }
+    }
+
+    /// Adds `delta` as the next step. After a step fails the rest are only
+    /// counted, so the failure reported is always the first.
+    pub fn apply(&mut self, delta: SignedInt) -> &mut Self {
+        let step = self.steps;
+        self.steps += 1;
+        if self.failed.is_some() {
+            return self;
+        }
+        if delta.is_nan() {
+            self.failed = Some((step, "delta is NaN".to_string()));
+            return self;
+        }
+        match self.running.checked_add(delta) {
+            Ok(running) => {
+                if !running.is_lt_zero() {
+                    self.negative_since = None;
+                } else if !self.running.is_lt_zero() {
+                    self.negative_since = Some(step);
+                }
+                self.running = running;
+            }
+            Err(err) => self.failed = Some((step, err.to_string())),
+        }
+        self
+    }
+
+    /// Applies every delta in order, see [`NonNegativeGuard::apply`]
+    pub fn apply_all(&mut self, deltas: impl IntoIterator<Item = SignedInt>) -> &mut Self {
+        for delta in deltas {
+            self.apply(delta);
+        }
+        self
+    }
+
+    /// Balance after the steps applied so far, possibly negative
+    pub fn running(&self) -> SignedInt {
+        self.running
+    }
+
+    /// The final balance, or the step that made the settlement fail. For a
+    /// negative result that is the step from which the balance stayed below
+    /// zero.
+    pub fn commit(self) -> CommonResult<Uint256> {
+        if let Some((step, reason)) = self.failed {
+            return Err(CommonError::SettlementStep { step, reason });
+        }
+        if let Some(step) = self.negative_since {
+            return Err(CommonError::SettlementStep {
+                step,
+                reason: format!("balance ends at {}", self.running.to_string()),
+            });
+        }
+        Ok(self.running.value)
+    }
+}
+
+#[test]
+fn test_non_negative_guard() {
+    use std::str::FromStr;
+
+    let int = |s: &str| SignedInt::from_str(s).unwrap();
+    let deltas = |list: &[&str]| list.iter().map(|s| int(s)).collect::<Vec<_>>();
+
+    // Dipping below zero part way is fine as long as it recovers
+    let mut guard = NonNegativeGuard::new(Uint256::from_u128(100));
+    guard.apply_all(deltas(&["-150", "30", "40"]));
+    assert_eq!(guard.running(), int("20"));
+    assert_eq!(guard.commit().unwrap(), Uint256::from_u128(20));
+
+    let mut guard = NonNegativeGuard::new(Uint256::from_u128(100));
+    guard
+        .apply(int("-150"))
+        .apply(int("60"))
+        .apply(int("-20"))
+        .apply(int("-5"));
+    assert_eq!(
+        guard.commit(),
+        Err(CommonError::SettlementStep {
+            step: 2,
+            reason: "balance ends at -15".to_string(),
+        })
+    );
+
+    // Ending at exactly zero commits
+    let mut guard = NonNegativeGuard::new(Uint256::from_u128(10));
+    guard.apply_all(deltas(&["-10"]));
+    assert_eq!(guard.commit().unwrap(), Uint256::zero());
+
+    // The first step that can't be applied wins over later ones
+    let mut guard = NonNegativeGuard::new(Uint256::MAX);
+    guard.apply_all([int("1"), SignedInt::nan(), int("-1")]);
+    assert!(matches!(
+        guard.commit(),
+        Err(CommonError::SettlementStep { step: 0, .. })
+    ));
+    let mut guard = NonNegativeGuard::new(Uint256::zero());
+    guard.apply_all([int("-1"), SignedInt::nan()]);
+    assert_eq!(
+        guard.commit(),
+        Err(CommonError::SettlementStep {
+            step: 1,
+            reason: "delta is NaN".to_string(),
+        })
+    );
+
+    assert_eq!(
+        NonNegativeGuard::new(Uint256::from_u128(5))
+            .commit()
+            .unwrap(),
+        Uint256::from_u128(5)
+    );
+}
@@ -0,0 +1,157 @@
+use std::str::FromStr;
+
+use num_traits::One;
+
+use crate::{
+    error::{CommonError, CommonResult, ParseSignedError},
+    ops::CheckedOps,
+};
+
+/// Totals for iterators of fallible values, such as one result per handled
+/// item. Both stop at the first error and return it, including an overflow
+/// of the running total.
+pub trait TryIteratorExt<T> {
+    /// Sum of all values, or the default (zero) for an empty iterator
+    fn try_sum(self) -> CommonResult<T>
+    where
+        T: Default;
+
+    /// Product of all values, or one for an empty iterator
+    fn try_product(self) -> CommonResult<T>
+    where
+        T: One;
+}
+
+impl<T, I> TryIteratorExt<T> for I
+where
+    T: CheckedOps,
+    I: Iterator<Item = CommonResult<T>>,
+{
+    fn try_sum(mut self) -> CommonResult<T>
+    where
+        T: Default,
+    {
+        self.try_fold(T::default(), |acc, value| acc.checked_add(value?))
+    }
+
+    fn try_product(mut self) -> CommonResult<T>
+    where
+        T: One,
+    {
+        self.try_fold(T::one(), |acc, value| acc.checked_mul(value?))
+    }
+}
+
+/// Parses every element of a message array, e.g. a list of SignedDecimal
+/// weights, naming the position of the first invalid one in the error
+pub fn parse_all<T>(values: Vec<String>) -> CommonResult<Vec<T>>
+where
+    T: FromStr<Err = ParseSignedError>,
+{
+    values
+        .iter()
+        .enumerate()
+        .map(|(index, value)| {
+            T::from_str(value).map_err(|source| CommonError::ParseAt { index, source })
+        })
+        .collect()
+}
+
+#[test]
+fn test_try_sum() {
+    use crate::{signed_decimal::SignedDecimal, signed_int::SignedInt};
+
+    let parse = |s: &&str| Ok(SignedDecimal::from_str(s)?);
+    let values = ["1.5", "-4", "0.25"];
+    assert_eq!(
+        values.iter().map(parse).try_sum().unwrap(),
+        SignedDecimal::from_str("-2.25").unwrap()
+    );
+    let empty: [&str; 0] = [];
+    assert_eq!(
+        empty.iter().map(parse).try_sum().unwrap(),
+        SignedDecimal::zero()
+    );
+
+    // The first error is returned and later items aren't evaluated
+    let mut evaluated = 0;
+    let result = ["1", "x", "y"]
+        .iter()
+        .map(|s| {
+            evaluated += 1;
+            Ok(SignedDecimal::from_str(s)?)
+        })
+        .try_sum();
+    assert!(matches!(result, Err(CommonError::Parse(_))));
+    assert_eq!(evaluated, 2);
+
+    let ints = [
+        Ok(SignedInt::from_str("-3").unwrap()),
+        Ok(SignedInt::from_u128(7)),
+    ];
+    assert_eq!(ints.into_iter().try_sum().unwrap(), SignedInt::from_u128(4));
+
+    // An overflowing running total is an error rather than a panic
+    let overflow = [Ok(SignedInt::MAX), Ok(SignedInt::from_u128(1))];
+    assert!(matches!(
+        overflow.into_iter().try_sum(),
+        Err(CommonError::Overflow(_))
+    ));
+}
+
+#[test]
+#[cfg(not(feature = "no-panic"))]
+fn test_try_product() {
+    use crate::signed_decimal::SignedDecimal;
+
+    let parse = |s: &&str| Ok(SignedDecimal::from_str(s)?);
+    let values = ["1.5", "-4", "0.25"];
+    assert_eq!(
+        values.iter().map(parse).try_product().unwrap(),
+        SignedDecimal::from_str("-1.5").unwrap()
+    );
+    let empty: [&str; 0] = [];
+    assert_eq!(
+        empty.iter().map(parse).try_product().unwrap(),
+        SignedDecimal::one()
+    );
+
+    let overflow = [Ok(SignedDecimal::MAX), Ok(SignedDecimal::from(2u64))];
+    assert!(matches!(
+        overflow.into_iter().try_product(),
+        Err(CommonError::Overflow(_))
+    ));
+}
+
+#[test]
+fn test_parse_all() {
+    use crate::{signed_decimal::SignedDecimal, signed_int::SignedInt};
+
+    let strings = |list: &[&str]| list.iter().map(|s| s.to_string()).collect::<Vec<_>>();
+
+    assert_eq!(
+        parse_all::<SignedDecimal>(strings(&["-1.5", "0", "2"])).unwrap(),
+        [
+            SignedDecimal::from_str("-1.5").unwrap(),
+            SignedDecimal::zero(),
+            SignedDecimal::from_str("2").unwrap(),
+        ]
+    );
+    assert_eq!(
+        parse_all::<SignedInt>(strings(&[])).unwrap(),
+        Vec::<SignedInt>::new()
+    );
+
+    let err = parse_all::<SignedInt>(strings(&["7", "-3", "1.5", "x"])).unwrap_err();
+    assert_eq!(
+        err,
+        CommonError::ParseAt {
+            index: 2,
+            source: ParseSignedError::InvalidCharacter { pos: 1 },
+        }
+    );
+    assert_eq!(
+        err.to_string(),
+        "Element 2: Invalid character at position 1, expected digits after an optional '-'"
+    );
+}
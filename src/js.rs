@@ -0,0 +1,85 @@
+use std::{cmp::Ordering, str::FromStr};
+
+use wasm_bindgen::prelude::*;
+
+use crate::signed_decimal::SignedDecimal;
+
+/// SignedDecimal for JavaScript, running the same arithmetic as the contract
+/// so previews match on-chain results exactly. Values cross the boundary as
+/// strings.
+#[wasm_bindgen(js_name = SignedDecimal)]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct JsSignedDecimal(SignedDecimal);
+
+#[wasm_bindgen(js_class = SignedDecimal)]
+impl JsSignedDecimal {
+    /// Parses a decimal string such as "-1.5", throwing on invalid input
+    pub fn parse(value: &str) -> Result<JsSignedDecimal, JsError> {
+        Ok(Self(SignedDecimal::from_str(value)?))
+    }
+
+    #[wasm_bindgen(js_name = toString)]
+    #[allow(clippy::inherent_to_string)]
+    pub fn to_string(&self) -> String {
+        self.0.to_string()
+    }
+
+    /// Sum, throwing on overflow instead of trapping the module
+    pub fn add(&self, other: &JsSignedDecimal) -> Result<JsSignedDecimal, JsError> {
+        Ok(Self(self.0.checked_add(other.0)?))
+    }
+
+    /// Difference, throwing on overflow
+    pub fn sub(&self, other: &JsSignedDecimal) -> Result<JsSignedDecimal, JsError> {
+        Ok(Self(self.0.checked_sub(other.0)?))
+    }
+
+    /// Product, throwing on overflow
+    pub fn mul(&self, other: &JsSignedDecimal) -> Result<JsSignedDecimal, JsError> {
+        Ok(Self(self.0.checked_mul(other.0)?))
+    }
+
+    /// -1, 0 or 1 as self is less than, equal to or greater than `other`,
+    /// for use with `Array.prototype.sort`
+    pub fn compare(&self, other: &JsSignedDecimal) -> i32 {
+        match self.0.cmp(&other.0) {
+            Ordering::Less => -1,
+            Ordering::Equal => 0,
+            Ordering::Greater => 1,
+        }
+    }
+}
+
+impl From<SignedDecimal> for JsSignedDecimal {
+    fn from(value: SignedDecimal) -> Self {
+        Self(value)
+    }
+}
+
+impl From<JsSignedDecimal> for SignedDecimal {
+    fn from(value: JsSignedDecimal) -> Self {
+        value.0
+    }
+}
+
+#[test]
+fn test_js_signed_decimal() {
+    // Errors become JS exceptions, which only exist on wasm targets, so only
+    // the successful paths run here
+    let dec = |s: &str| JsSignedDecimal::parse(s).unwrap();
+
+    let entry = dec("1.25");
+    let exit = dec("1.1");
+    let size = dec("-40");
+    let pnl = exit.sub(&entry).unwrap().mul(&size).unwrap();
+    assert_eq!(pnl.to_string(), "6");
+    assert_eq!(pnl.add(&dec("-6.5")).unwrap().to_string(), "-0.5");
+
+    assert_eq!(entry.compare(&exit), 1);
+    assert_eq!(size.compare(&exit), -1);
+    assert_eq!(dec("-0").compare(&dec("0")), 0);
+    assert_eq!(
+        SignedDecimal::from(dec("-2.50")),
+        SignedDecimal::from_str("-2.5").unwrap()
+    );
+}
@@ -0,0 +1,182 @@
+use std::{convert::TryFrom, str::FromStr};
+
+use serde_json::Number;
+
+use crate::{
+    error::{CommonError, CommonResult},
+    signed_decimal::SignedDecimal,
+    signed_int::SignedInt,
+};
+
+/// Exact decimal text of a JSON number. Integers are always exact, but other
+/// numbers are only kept as written with the `arbitrary_precision` feature,
+/// and are refused otherwise rather than rounded through f64.
+fn number_text(number: &Number) -> CommonResult<String> {
+    if let Some(n) = number.as_u64() {
+        return Ok(n.to_string());
+    }
+    if let Some(n) = number.as_i64() {
+        return Ok(n.to_string());
+    }
+    if cfg!(feature = "arbitrary_precision") {
+        expand_exponent(&number.to_string())
+    } else {
+        Err(CommonError::Generic(format!(
+            "JSON number {number} would lose precision as f64, send it as a string \
+             or enable the arbitrary_precision feature"
+        )))
+    }
+}
+
+/// Largest exponent worth expanding. Past 78 integer digits or 18 decimal
+/// places plus the mantissa's own digits nothing parses anyway, so larger
+/// exponents are refused before any zeros are written out.
+const MAX_EXPONENT: i64 = 78 + SignedDecimal::DECIMAL_PLACES as i64;
+
+/// Rewrites "-1.25e3" as "-1250", without trailing fractional zeros, since
+/// the FromStr impls don't accept exponents
+fn expand_exponent(text: &str) -> CommonResult<String> {
+    let (mantissa, exp) = match text.find(['e', 'E']) {
+        Some(i) => {
+            let exp = text[i + 1..].parse::<i64>().map_err(|_| {
+                CommonError::Generic(format!("Invalid exponent in JSON number {text}"))
+            })?;
+            (&text[..i], exp)
+        }
+        None => (text, 0),
+    };
+    if exp.abs() > MAX_EXPONENT {
+        return Err(CommonError::Generic(format!(
+            "Exponent of JSON number {text} is out of range"
+        )));
+    }
+    let (sign, mantissa) = match mantissa.strip_prefix('-') {
+        Some(rest) => ("-", rest),
+        None => ("", mantissa),
+    };
+    let (whole, fraction) = mantissa.split_once('.').unwrap_or((mantissa, ""));
+    let digits = format!("{whole}{fraction}");
+    // Position of the decimal point within `digits`
+    let point = whole.len() as i64 + exp;
+    let (whole, fraction) = if point <= 0 {
+        (
+            "0".to_string(),
+            "0".repeat(point.unsigned_abs() as usize) + &digits,
+        )
+    } else if point as usize >= digits.len() {
+        (
+            digits.clone() + &"0".repeat(point as usize - digits.len()),
+            String::new(),
+        )
+    } else {
+        let (w, f) = digits.split_at(point as usize);
+        (w.to_string(), f.to_string())
+    };
+    let whole = whole.trim_start_matches('0');
+    let whole = if whole.is_empty() { "0" } else { whole };
+    let fraction = fraction.trim_end_matches('0');
+    if fraction.is_empty() {
+        Ok(format!("{sign}{whole}"))
+    } else {
+        Ok(format!("{sign}{whole}.{fraction}"))
+    }
+}
+
+impl TryFrom<Number> for SignedDecimal {
+    type Error = CommonError;
+
+    fn try_from(number: Number) -> CommonResult<Self> {
+        Ok(Self::from_str(&number_text(&number)?)?)
+    }
+}
+
+impl TryFrom<Number> for SignedInt {
+    type Error = CommonError;
+
+    fn try_from(number: Number) -> CommonResult<Self> {
+        Ok(Self::from_str(&number_text(&number)?)?)
+    }
+}
+
+#[test]
+fn test_json_number() {
+    let number = |s: &str| serde_json::from_str::<Number>(s).unwrap();
+    let dec = |s: &str| SignedDecimal::from_str(s).unwrap();
+
+    assert_eq!(SignedDecimal::try_from(number("-42")).unwrap(), dec("-42"));
+    assert_eq!(
+        SignedInt::try_from(number("18446744073709551615")).unwrap(),
+        SignedInt::from_u128(u64::MAX as u128)
+    );
+
+    // JSON numbers are accepted only where a field opts in
+    assert_eq!(
+        serde_json::from_str::<Payload>(r#"{"pnl":-7}"#)
+            .unwrap()
+            .pnl,
+        dec("-7")
+    );
+    assert_eq!(
+        serde_json::from_str::<Payload>(r#"{"pnl":"-7.5"}"#)
+            .unwrap()
+            .pnl,
+        dec("-7.5")
+    );
+    assert!(serde_json::from_str::<Payload>(r#"{"pnl":true}"#).is_err());
+    assert!(serde_json::from_str::<SignedDecimal>("-7").is_err());
+
+    let expand = |s: &str| expand_exponent(s).unwrap();
+    assert_eq!(expand("-1.25e3"), "-1250");
+    assert_eq!(expand("1.5E-7"), "0.00000015");
+    assert_eq!(expand("0.0100"), "0.01");
+    assert_eq!(expand("12e+2"), "1200");
+    assert_eq!(expand("123.456e-1"), "12.3456");
+    assert_eq!(expand("1e-96"), format!("0.{}1", "0".repeat(95)));
+
+    // Untrusted exponents are bounded before any zeros are written
+    assert!(expand_exponent("1e9223372036854775807").is_err());
+    assert!(expand_exponent("1e-200000000").is_err());
+    assert!(expand_exponent("1e97").is_err());
+    assert!(expand_exponent("1e").is_err());
+    assert!(expand_exponent("1e99999999999999999999").is_err());
+}
+
+#[cfg(test)]
+#[derive(serde::Deserialize)]
+struct Payload {
+    #[serde(deserialize_with = "crate::serde_helpers::deserialize_json_number")]
+    pnl: SignedDecimal,
+}
+
+#[test]
+#[cfg(not(feature = "arbitrary_precision"))]
+fn test_json_number_rejects_floats() {
+    assert!(SignedDecimal::try_from(Number::from_f64(0.1).unwrap()).is_err());
+    assert!(serde_json::from_str::<Payload>(r#"{"pnl":0.1}"#).is_err());
+}
+
+#[test]
+#[cfg(feature = "arbitrary_precision")]
+fn test_json_number_arbitrary_precision() {
+    let dec = |s: &str| SignedDecimal::from_str(s).unwrap();
+
+    // Too many digits for f64, so these only survive as written
+    let text = "-123456789012345678901234567890.123456789012345678";
+    let number: Number = serde_json::from_str(text).unwrap();
+    assert_eq!(SignedDecimal::try_from(number).unwrap(), dec(text));
+    let payload = |pnl: &str| serde_json::from_str::<Payload>(&format!(r#"{{"pnl":{pnl}}}"#));
+    assert_eq!(payload(text).unwrap().pnl, dec(text));
+    assert_eq!(payload("2.5e-17").unwrap().pnl, dec("0.000000000000000025"));
+    assert!(payload("1e9223372036854775807").is_err());
+
+    let value: serde_json::Value = serde_json::from_str(r#"{"pnl": 0.1}"#).unwrap();
+    assert_eq!(
+        serde_json::from_value::<Payload>(value).unwrap().pnl,
+        dec("0.1")
+    );
+
+    let int = "-115792089237316195423570985008687907853269984665640564039457584007913129639935";
+    let number: Number = serde_json::from_str(int).unwrap();
+    assert_eq!(SignedInt::try_from(number).unwrap(), SignedInt::MIN);
+    assert!(SignedInt::try_from(serde_json::from_str::<Number>("1.5").unwrap()).is_err());
+}
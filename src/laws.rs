@@ -0,0 +1,117 @@
+/// Generates tests checking the arithmetic laws every signed type in this
+/// crate should obey: commutativity, associativity, distributivity,
+/// identities, sign rules, ordering and the string round trip.
+///
+/// The type must implement `num_traits::Signed`, `FromStr`, `ToString`,
+/// `PartialOrd`, `Copy` and `Debug`. Samples are small whole numbers so the
+/// laws hold exactly for both integer and decimal types.
+///
+/// `signed_arith_laws!(MyType)` adds the tests to the current module, while
+/// `signed_arith_laws!(my_type_laws, MyType)` wraps them in a new module so
+/// several types can be covered side by side.
+#[macro_export]
+macro_rules! signed_arith_laws {
+    ($name:ident, $t:ty) => {
+        #[cfg(test)]
+        mod $name {
+            #[allow(unused_imports)]
+            use super::*;
+
+            $crate::signed_arith_laws!($t);
+        }
+    };
+    ($t:ty) => {
+        #[cfg(test)]
+        fn arith_law_samples() -> Vec<$t> {
+            ["0", "1", "-1", "2", "-3", "7", "-12", "100"]
+                .iter()
+                .map(|s| <$t as std::str::FromStr>::from_str(s).ok().unwrap())
+                .collect()
+        }
+
+        #[test]
+        fn arith_law_commutative() {
+            for &a in &arith_law_samples() {
+                for &b in &arith_law_samples() {
+                    assert_eq!(a + b, b + a, "{:?} + {:?}", a, b);
+                    assert_eq!(a * b, b * a, "{:?} * {:?}", a, b);
+                }
+            }
+        }
+
+        #[test]
+        fn arith_law_associative_distributive() {
+            for &a in &arith_law_samples() {
+                for &b in &arith_law_samples() {
+                    for &c in &arith_law_samples() {
+                        assert_eq!((a + b) + c, a + (b + c), "{:?} {:?} {:?}", a, b, c);
+                        assert_eq!((a * b) * c, a * (b * c), "{:?} {:?} {:?}", a, b, c);
+                        assert_eq!(a * (b + c), a * b + a * c, "{:?} {:?} {:?}", a, b, c);
+                        assert_eq!(a - (b - c), (a - b) + c, "{:?} {:?} {:?}", a, b, c);
+                    }
+                }
+            }
+        }
+
+        #[test]
+        fn arith_law_identities() {
+            use $crate::num_traits::{One, Zero};
+
+            let zero = <$t as Zero>::zero();
+            let one = <$t as One>::one();
+            for &a in &arith_law_samples() {
+                assert_eq!(a + zero, a);
+                assert_eq!(a * one, a);
+                assert_eq!(a - a, zero);
+                assert_eq!(a + (-a), zero);
+                assert_eq!(a * zero, zero);
+                for &b in &arith_law_samples() {
+                    if !Zero::is_zero(&b) {
+                        assert_eq!((a * b) / b, a, "{:?} * {:?} / {:?}", a, b, b);
+                    }
+                }
+            }
+        }
+
+        #[test]
+        fn arith_law_signs() {
+            use $crate::num_traits::{Signed, Zero};
+
+            for &a in &arith_law_samples() {
+                assert_eq!(-(-a), a);
+                assert!(!a.abs().is_negative());
+                assert_eq!(a.signum() * a.abs(), a);
+                assert!(!(a.is_positive() && a.is_negative()), "{:?}", a);
+                assert_eq!(a.is_negative(), a < <$t as Zero>::zero(), "{:?}", a);
+                for &b in &arith_law_samples() {
+                    assert_eq!((-a) * b, -(a * b), "{:?} {:?}", a, b);
+                    assert_eq!((-a) * (-b), a * b, "{:?} {:?}", a, b);
+                    assert_eq!(a - b, -(b - a), "{:?} {:?}", a, b);
+                }
+            }
+        }
+
+        #[test]
+        fn arith_law_ordering() {
+            use $crate::num_traits::{Signed, Zero};
+
+            for &a in &arith_law_samples() {
+                for &b in &arith_law_samples() {
+                    let diff = b - a;
+                    assert_eq!(a < b, (a - b).is_negative(), "{:?} {:?}", a, b);
+                    assert_eq!(a == b, Zero::is_zero(&diff), "{:?} {:?}", a, b);
+                }
+            }
+        }
+
+        #[test]
+        fn arith_law_string_round_trip() {
+            for &a in &arith_law_samples() {
+                let parsed = <$t as std::str::FromStr>::from_str(&a.to_string())
+                    .ok()
+                    .unwrap();
+                assert_eq!(parsed, a);
+            }
+        }
+    };
+}
@@ -1,3 +1,72 @@
+pub mod aggregate;
+#[cfg(feature = "arrow")]
+pub mod arrow;
+pub mod audit;
+#[cfg(feature = "bigdecimal")]
+pub mod big_decimal;
+#[cfg(feature = "num-bigint")]
+pub mod big_int;
+pub mod bounded;
+pub mod clmm;
+pub mod codec;
+pub mod controller;
+#[cfg(feature = "cosmwasm_1_3")]
+pub mod cosmwasm_ints;
+#[cfg(feature = "cw20")]
+pub mod cw20_adapter;
+#[cfg(all(test, feature = "differential"))]
+mod differential;
 pub mod error;
+#[cfg(any(feature = "ethers", feature = "alloy"))]
+mod evm;
+pub mod flow_limiter;
+#[cfg(feature = "async-graphql")]
+pub mod graphql;
+pub mod guard;
+pub mod iter;
+#[cfg(feature = "js")]
+pub mod js;
+#[cfg(feature = "json")]
+mod json;
+mod laws;
+pub mod ops;
+#[cfg(feature = "rayon")]
+pub mod parallel;
+#[cfg(feature = "sqlx")]
+pub mod postgres;
+pub mod precision;
+#[cfg(feature = "proto")]
+pub mod proto;
+#[cfg(feature = "python")]
+mod python;
+pub mod querier;
+pub mod rate;
+pub mod response;
+pub mod reward_index;
+pub mod serde_helpers;
+pub mod signed_coin;
 pub mod signed_decimal;
 pub mod signed_int;
+pub mod signed_int512;
+#[cfg(feature = "cw-storage-plus")]
+pub mod storage_key;
+pub mod tick;
+pub mod trig;
+#[cfg(any(feature = "primitive-types", feature = "ruint"))]
+pub mod u256;
+#[cfg(feature = "json")]
+pub mod util;
+#[cfg(feature = "formatting")]
+pub mod words;
+
+// For the exported signed_arith_laws! macro
+#[doc(hidden)]
+pub use num_traits;
+
+// Values are shared freely between threads off-chain, so keep them Send + Sync
+const _: () = {
+    const fn assert_send_sync<T: Send + Sync>() {}
+    assert_send_sync::<signed_decimal::SignedDecimal>();
+    assert_send_sync::<signed_int::SignedInt>();
+    assert_send_sync::<error::CommonError>();
+};
@@ -0,0 +1,276 @@
+//! Arithmetic grouped by overflow policy, so generic code has to pick one.
+//!
+//! The `+`, `-` and `*` operators are the [`StrictOps`] methods and panic on
+//! overflow. `/` is the exception: it still returns zero for a zero divisor,
+//! which [`StrictOps::strict_div`] turns into a panic.
+//!
+//! [`SaturatingInto`] applies the saturating policy to conversions between
+//! widths.
+
+use std::convert::TryInto;
+
+use cosmwasm_std::{Uint256, Uint512};
+
+use crate::{error::CommonResult, signed_decimal::SignedDecimal, signed_int::SignedInt};
+
+/// Operations that panic when the result doesn't fit
+pub trait StrictOps: Sized {
+    fn strict_add(self, rhs: Self) -> Self;
+    fn strict_sub(self, rhs: Self) -> Self;
+    fn strict_mul(self, rhs: Self) -> Self;
+    fn strict_div(self, rhs: Self) -> Self;
+}
+
+/// Operations that return an error when the result doesn't fit
+pub trait CheckedOps: Sized {
+    fn checked_add(self, rhs: Self) -> CommonResult<Self>;
+    fn checked_sub(self, rhs: Self) -> CommonResult<Self>;
+    fn checked_mul(self, rhs: Self) -> CommonResult<Self>;
+    fn checked_div(self, rhs: Self) -> CommonResult<Self>;
+}
+
+/// Operations that clamp to the type's minimum or maximum when the result
+/// doesn't fit
+pub trait SaturatingOps: Sized {
+    fn saturating_add(self, rhs: Self) -> Self;
+    fn saturating_sub(self, rhs: Self) -> Self;
+    fn saturating_mul(self, rhs: Self) -> Self;
+    fn saturating_div(self, rhs: Self) -> Self;
+}
+
+#[cfg(not(feature = "no-panic"))]
+impl StrictOps for SignedInt {
+    fn strict_add(self, rhs: Self) -> Self {
+        SignedInt::strict_add(self, rhs)
+    }
+
+    fn strict_sub(self, rhs: Self) -> Self {
+        SignedInt::strict_sub(self, rhs)
+    }
+
+    fn strict_mul(self, rhs: Self) -> Self {
+        SignedInt::strict_mul(self, rhs)
+    }
+
+    fn strict_div(self, rhs: Self) -> Self {
+        SignedInt::strict_div(self, rhs)
+    }
+}
+
+impl CheckedOps for SignedInt {
+    fn checked_add(self, rhs: Self) -> CommonResult<Self> {
+        SignedInt::checked_add(self, rhs)
+    }
+
+    fn checked_sub(self, rhs: Self) -> CommonResult<Self> {
+        SignedInt::checked_sub(self, rhs)
+    }
+
+    fn checked_mul(self, rhs: Self) -> CommonResult<Self> {
+        SignedInt::checked_mul(self, rhs)
+    }
+
+    fn checked_div(self, rhs: Self) -> CommonResult<Self> {
+        SignedInt::checked_div(self, rhs)
+    }
+}
+
+impl SaturatingOps for SignedInt {
+    fn saturating_add(self, rhs: Self) -> Self {
+        SignedInt::saturating_add(self, rhs)
+    }
+
+    fn saturating_sub(self, rhs: Self) -> Self {
+        SignedInt::saturating_sub(self, rhs)
+    }
+
+    fn saturating_mul(self, rhs: Self) -> Self {
+        SignedInt::saturating_mul(self, rhs)
+    }
+
+    fn saturating_div(self, rhs: Self) -> Self {
+        SignedInt::saturating_div(self, rhs)
+    }
+}
+
+#[cfg(not(feature = "no-panic"))]
+impl StrictOps for SignedDecimal {
+    fn strict_add(self, rhs: Self) -> Self {
+        SignedDecimal::strict_add(self, rhs)
+    }
+
+    fn strict_sub(self, rhs: Self) -> Self {
+        SignedDecimal::strict_sub(self, rhs)
+    }
+
+    fn strict_mul(self, rhs: Self) -> Self {
+        SignedDecimal::strict_mul(self, rhs)
+    }
+
+    fn strict_div(self, rhs: Self) -> Self {
+        SignedDecimal::strict_div(self, rhs)
+    }
+}
+
+impl CheckedOps for SignedDecimal {
+    fn checked_add(self, rhs: Self) -> CommonResult<Self> {
+        SignedDecimal::checked_add(self, rhs)
+    }
+
+    fn checked_sub(self, rhs: Self) -> CommonResult<Self> {
+        SignedDecimal::checked_sub(self, rhs)
+    }
+
+    fn checked_mul(self, rhs: Self) -> CommonResult<Self> {
+        SignedDecimal::checked_mul(self, rhs)
+    }
+
+    fn checked_div(self, rhs: Self) -> CommonResult<Self> {
+        SignedDecimal::checked_div(self, rhs)
+    }
+}
+
+impl SaturatingOps for SignedDecimal {
+    fn saturating_add(self, rhs: Self) -> Self {
+        SignedDecimal::saturating_add(self, rhs)
+    }
+
+    fn saturating_sub(self, rhs: Self) -> Self {
+        SignedDecimal::saturating_sub(self, rhs)
+    }
+
+    fn saturating_mul(self, rhs: Self) -> Self {
+        SignedDecimal::saturating_mul(self, rhs)
+    }
+
+    fn saturating_div(self, rhs: Self) -> Self {
+        SignedDecimal::saturating_div(self, rhs)
+    }
+}
+
+/// Conversion that clamps to the target's minimum or maximum when the value
+/// doesn't fit, e.g. for telemetry and events where an error isn't worth
+/// handling. NaN becomes zero.
+pub trait SaturatingInto<T> {
+    fn saturating_into(self) -> T;
+}
+
+/// Result of converting `value`, or the bound on the side of its sign when
+/// the conversion failed
+pub(crate) fn saturate<T: Default, E>(
+    value: SignedInt,
+    converted: Result<T, E>,
+    min: T,
+    max: T,
+) -> T {
+    converted.unwrap_or_else(|_| {
+        if value.is_nan() {
+            T::default()
+        } else if value.is_positive {
+            max
+        } else {
+            min
+        }
+    })
+}
+
+macro_rules! impl_saturating_into {
+    ($($to:ty: $min:expr, $max:expr;)*) => {$(
+        impl SaturatingInto<$to> for SignedInt {
+            fn saturating_into(self) -> $to {
+                saturate(self, TryInto::<$to>::try_into(self), $min, $max)
+            }
+        }
+    )*};
+}
+
+impl_saturating_into! {
+    i64: i64::MIN, i64::MAX;
+    i128: i128::MIN, i128::MAX;
+    u128: 0, u128::MAX;
+    Uint256: Uint256::zero(), Uint256::MAX;
+    SignedDecimal: SignedDecimal::MIN, SignedDecimal::MAX;
+}
+
+impl SaturatingInto<SignedInt> for Uint512 {
+    fn saturating_into(self) -> SignedInt {
+        Uint256::try_from(self).map_or(SignedInt::MAX, SignedInt::from)
+    }
+}
+
+#[test]
+fn test_overflow_policies() {
+    use std::str::FromStr;
+
+    fn total<T: CheckedOps + Copy>(values: &[T]) -> CommonResult<T> {
+        let (first, rest) = values.split_first().unwrap();
+        rest.iter().try_fold(*first, |acc, x| acc.checked_add(*x))
+    }
+
+    fn clamped_total<T: SaturatingOps + Copy>(values: &[T]) -> T {
+        let (first, rest) = values.split_first().unwrap();
+        rest.iter().fold(*first, |acc, x| acc.saturating_add(*x))
+    }
+
+    let dec = |s: &str| SignedDecimal::from_str(s).unwrap();
+    let max = SignedDecimal::MAX;
+
+    assert_eq!(total(&[dec("1.5"), dec("-4")]).unwrap(), dec("-2.5"));
+    assert!(total(&[max, dec("1")]).is_err());
+    assert_eq!(
+        clamped_total(&[max, dec("1"), dec("-1")]),
+        max.checked_sub(dec("1")).unwrap()
+    );
+    assert_eq!(
+        SaturatingOps::saturating_mul(SignedDecimal::MIN, dec("2")),
+        SignedDecimal::MIN
+    );
+    assert_eq!(
+        SaturatingOps::saturating_sub(SignedDecimal::MIN, dec("-1")),
+        SignedDecimal::MIN.checked_add(dec("1")).unwrap()
+    );
+    assert_eq!(
+        SaturatingOps::saturating_sub(SignedDecimal::MIN, dec("1")),
+        SignedDecimal::MIN
+    );
+
+    let int = SignedInt::from_u128;
+    assert!(total(&[SignedInt::MAX, int(1)]).is_err());
+    assert_eq!(clamped_total(&[SignedInt::MIN, -int(1)]), SignedInt::MIN);
+    #[cfg(not(feature = "no-panic"))]
+    {
+        assert_eq!(dec("-3").strict_div(dec("2")), dec("-1.5"));
+        assert!(std::panic::catch_unwind(|| dec("1").strict_div(dec("0"))).is_err());
+        assert_eq!(int(7).strict_div(-int(2)), -int(3));
+        assert!(std::panic::catch_unwind(|| int(7).strict_div(int(0))).is_err());
+        assert!(std::panic::catch_unwind(|| SignedInt::MAX.strict_add(int(1))).is_err());
+    }
+}
+
+#[test]
+fn test_saturating_into() {
+    let int = |s: &str| <SignedInt as std::str::FromStr>::from_str(s).unwrap();
+
+    let narrow: i64 = int("-42").saturating_into();
+    assert_eq!(narrow, -42);
+    let narrow: i64 = SignedInt::MIN.saturating_into();
+    assert_eq!(narrow, i64::MIN);
+    let narrow: i128 = SignedInt::MAX.saturating_into();
+    assert_eq!(narrow, i128::MAX);
+    let narrow: i128 = SignedInt::nan().saturating_into();
+    assert_eq!(narrow, 0);
+    let unsigned: u128 = int("-1").saturating_into();
+    assert_eq!(unsigned, 0);
+    let unsigned: Uint256 = SignedInt::MAX.saturating_into();
+    assert_eq!(unsigned, Uint256::MAX);
+
+    let dec: SignedDecimal = SignedInt::MIN.saturating_into();
+    assert_eq!(dec, SignedDecimal::MIN);
+    let dec: SignedDecimal = int("-3").saturating_into();
+    assert_eq!(dec, SignedDecimal::from_int(-3));
+
+    let wide: SignedInt = Uint512::MAX.saturating_into();
+    assert_eq!(wide, SignedInt::MAX);
+    let wide: SignedInt = Uint512::from(5u8).saturating_into();
+    assert_eq!(wide, int("5"));
+}
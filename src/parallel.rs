@@ -0,0 +1,88 @@
+use rayon::prelude::*;
+
+/// Sum of `values`, added across rayon's thread pool. Panics on overflow like
+/// the `+` operator.
+#[cfg(not(feature = "no-panic"))]
+pub fn par_sum<T>(values: &[T]) -> T
+where
+    T: num_traits::Zero + Copy + Send + Sync,
+{
+    values.par_iter().copied().reduce(T::zero, |a, b| a + b)
+}
+
+/// Smallest of `values`, or `None` when empty
+pub fn par_min<T>(values: &[T]) -> Option<T>
+where
+    T: Ord + Copy + Send + Sync,
+{
+    values.par_iter().copied().min()
+}
+
+/// Largest of `values`, or `None` when empty
+pub fn par_max<T>(values: &[T]) -> Option<T>
+where
+    T: Ord + Copy + Send + Sync,
+{
+    values.par_iter().copied().max()
+}
+
+#[test]
+fn test_parallel_reductions() {
+    use std::str::FromStr;
+
+    use crate::{signed_decimal::SignedDecimal, signed_int::SignedInt};
+
+    let values: Vec<SignedDecimal> = (0..10_000u32)
+        .map(|i| {
+            let x = SignedDecimal::from_str(&format!("{}.5", i)).unwrap();
+            if i % 3 == 0 {
+                -x
+            } else {
+                x
+            }
+        })
+        .collect();
+    assert_eq!(
+        par_min(&values),
+        Some(SignedDecimal::from_str("-9999.5").unwrap())
+    );
+    assert_eq!(
+        par_max(&values),
+        Some(SignedDecimal::from_str("9998.5").unwrap())
+    );
+
+    let ints: Vec<SignedInt> = ["-7", "3", "0", "-2"]
+        .iter()
+        .map(|s| SignedInt::from_str(s).unwrap())
+        .collect();
+    assert_eq!(par_min(&ints), Some(SignedInt::from_str("-7").unwrap()));
+    assert_eq!(par_max::<SignedInt>(&[]), None);
+}
+
+#[test]
+#[cfg(not(feature = "no-panic"))]
+fn test_par_sum() {
+    use std::str::FromStr;
+
+    use crate::{signed_decimal::SignedDecimal, signed_int::SignedInt};
+
+    let values: Vec<SignedDecimal> = (0..10_000u32)
+        .map(|i| {
+            let x = SignedDecimal::from_str(&format!("{}.5", i)).unwrap();
+            if i % 3 == 0 {
+                -x
+            } else {
+                x
+            }
+        })
+        .collect();
+    let sequential = values.iter().fold(SignedDecimal::zero(), |a, b| a + *b);
+    assert_eq!(par_sum(&values), sequential);
+
+    let ints: Vec<SignedInt> = ["-7", "3", "0", "-2"]
+        .iter()
+        .map(|s| SignedInt::from_str(s).unwrap())
+        .collect();
+    assert_eq!(par_sum(&ints), SignedInt::from_str("-6").unwrap());
+    assert_eq!(par_sum::<SignedInt>(&[]), SignedInt::zero());
+}
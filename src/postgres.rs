@@ -0,0 +1,103 @@
+use std::str::FromStr;
+
+use sqlx::{
+    decode::Decode,
+    encode::{Encode, IsNull},
+    error::BoxDynError,
+    postgres::{PgArgumentBuffer, PgTypeInfo, PgValueRef, Postgres},
+    types::{BigDecimal, Type},
+    ValueRef,
+};
+
+use crate::{signed_decimal::SignedDecimal, signed_int::SignedInt};
+
+/// Parses a NUMERIC through its plain form, which never uses an exponent.
+/// Trailing zeros are dropped first so that a column with a scale beyond 18
+/// still reads any value that fits.
+fn from_numeric<T: FromStr>(value: PgValueRef<'_>) -> Result<T, BoxDynError>
+where
+    T::Err: std::error::Error + Send + Sync + 'static,
+{
+    let numeric = BigDecimal::decode(value)?;
+    Ok(T::from_str(&numeric.normalized().to_plain_string())?)
+}
+
+impl Type<Postgres> for SignedDecimal {
+    fn type_info() -> PgTypeInfo {
+        <BigDecimal as Type<Postgres>>::type_info()
+    }
+}
+
+impl Encode<'_, Postgres> for SignedDecimal {
+    fn encode_by_ref(&self, buf: &mut PgArgumentBuffer) -> Result<IsNull, BoxDynError> {
+        BigDecimal::from_str(&self.to_string())?.encode_by_ref(buf)
+    }
+}
+
+impl Decode<'_, Postgres> for SignedDecimal {
+    fn decode(value: PgValueRef<'_>) -> Result<Self, BoxDynError> {
+        from_numeric(value)
+    }
+}
+
+/// Written as NUMERIC, and read from either NUMERIC or TEXT columns
+impl Type<Postgres> for SignedInt {
+    fn type_info() -> PgTypeInfo {
+        <BigDecimal as Type<Postgres>>::type_info()
+    }
+
+    fn compatible(ty: &PgTypeInfo) -> bool {
+        <BigDecimal as Type<Postgres>>::compatible(ty) || <&str as Type<Postgres>>::compatible(ty)
+    }
+}
+
+impl Encode<'_, Postgres> for SignedInt {
+    fn encode_by_ref(&self, buf: &mut PgArgumentBuffer) -> Result<IsNull, BoxDynError> {
+        if self.is_nan() {
+            return Err("Cannot store a NaN SignedInt as NUMERIC".into());
+        }
+        BigDecimal::from_str(&self.to_string())?.encode_by_ref(buf)
+    }
+}
+
+impl Decode<'_, Postgres> for SignedInt {
+    fn decode(value: PgValueRef<'_>) -> Result<Self, BoxDynError> {
+        if <BigDecimal as Type<Postgres>>::compatible(&value.type_info()) {
+            from_numeric(value)
+        } else {
+            Ok(Self::from_str(<&str>::decode(value)?)?)
+        }
+    }
+}
+
+#[test]
+fn test_postgres_types() {
+    use cosmwasm_std::Uint256;
+
+    fn encoded<'q, T: Encode<'q, Postgres>>(value: T) -> Vec<u8> {
+        let mut buf = PgArgumentBuffer::default();
+        assert!(matches!(value.encode_by_ref(&mut buf).unwrap(), IsNull::No));
+        buf.to_vec()
+    }
+
+    let numeric = |s: &str| encoded(BigDecimal::from_str(s).unwrap());
+
+    for s in ["-100.5", "0.000000000000000001", "0.0"] {
+        assert_eq!(encoded(SignedDecimal::from_str(s).unwrap()), numeric(s));
+    }
+    assert_eq!(encoded(SignedInt::from_str("-42").unwrap()), numeric("-42"));
+    assert_eq!(encoded(SignedInt::MAX), numeric(&Uint256::MAX.to_string()));
+
+    let nan = SignedInt {
+        value: Uint256::zero(),
+        is_positive: false,
+    };
+    assert!(nan.encode_by_ref(&mut PgArgumentBuffer::default()).is_err());
+
+    let numeric_type = <BigDecimal as Type<Postgres>>::type_info();
+    let text_type = <String as Type<Postgres>>::type_info();
+    assert!(<SignedInt as Type<Postgres>>::compatible(&numeric_type));
+    assert!(<SignedInt as Type<Postgres>>::compatible(&text_type));
+    assert!(<SignedDecimal as Type<Postgres>>::compatible(&numeric_type));
+    assert!(!<SignedDecimal as Type<Postgres>>::compatible(&text_type));
+}
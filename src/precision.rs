@@ -0,0 +1,119 @@
+use cosmwasm_std::{Decimal256, DivideByZeroError, Uint256};
+
+use crate::error::CommonResult;
+
+/// Worst-case rounding error of `a * b / c` evaluated with the [`SignedDecimal`]
+/// operators, given bounds on the operand magnitudes.
+///
+/// Each operator truncates toward zero, losing less than one atomic, and an
+/// earlier loss is scaled by whatever the intermediate result is multiplied or
+/// divided by afterwards. Neither the signs nor the magnitude of `a` affect
+/// the bounds.
+///
+/// [`SignedDecimal`]: crate::signed_decimal::SignedDecimal
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct MulDivPrecision {
+    /// Largest possible |b|
+    pub max_b: Decimal256,
+    /// Smallest possible |c|
+    pub min_c: Decimal256,
+}
+
+impl MulDivPrecision {
+    /// Maximum error in atomics of `a * b / c`, multiplying first.
+    ///
+    /// The product loses under one atomic, which dividing by `c` turns into
+    /// under `1 / c` atomics, plus under one atomic lost by the division.
+    pub fn max_error(&self) -> CommonResult<Uint256> {
+        let one = Decimal256::one().atomics();
+        let c = self.min_c.atomics();
+        let scaled = one
+            .checked_add(c)?
+            .checked_sub(Uint256::from_u128(1))?
+            .checked_div(c)
+            .map_err(|_| DivideByZeroError::new(self.min_c))?;
+        Ok(scaled.checked_add(Uint256::from_u128(1))?)
+    }
+
+    /// Maximum error in atomics of `a / c * b`, dividing first.
+    ///
+    /// The quotient loses under one atomic, which multiplying by `b` turns
+    /// into under `b` atomics, plus under one atomic lost by the product. This
+    /// is usually worse than [`MulDivPrecision::max_error`] but can't overflow
+    /// where `a * b` would.
+    pub fn max_error_div_first(&self) -> CommonResult<Uint256> {
+        if self.min_c.is_zero() {
+            return Err(DivideByZeroError::new(self.min_c).into());
+        }
+        let b = self.max_b.atomics();
+        let one = Decimal256::one().atomics();
+        let scaled = b.checked_add(one - Uint256::from_u128(1))? / one;
+        Ok(scaled.checked_add(Uint256::from_u128(1))?)
+    }
+}
+
+#[test]
+fn test_mul_div_precision() {
+    use std::str::FromStr;
+
+    use crate::signed_decimal::SignedDecimal;
+
+    let dec = |s: &str| Decimal256::from_str(s).unwrap();
+    let bound = MulDivPrecision {
+        max_b: dec("250.5"),
+        min_c: dec("0.003"),
+    };
+    assert_eq!(bound.max_error().unwrap(), Uint256::from_u128(335));
+    assert_eq!(
+        bound.max_error_div_first().unwrap(),
+        Uint256::from_u128(252)
+    );
+
+    let exact = MulDivPrecision {
+        min_c: dec("1"),
+        ..bound
+    };
+    assert_eq!(exact.max_error().unwrap(), Uint256::from_u128(2));
+
+    let zero = MulDivPrecision {
+        min_c: Decimal256::zero(),
+        ..bound
+    };
+    assert!(zero.max_error().is_err());
+    assert!(zero.max_error_div_first().is_err());
+
+    // The error actually observed stays within the bound
+    for (a, b, c) in [
+        ("999.999999999999999999", "250.5", "0.003"),
+        ("-0.000000000000000007", "3.3", "0.003000000000000001"),
+        ("123.456", "-0.000000000000000001", "7.77"),
+        ("1000", "249.999999999999999999", "-0.003"),
+    ] {
+        let (a, b, c) = (
+            SignedDecimal::from_str(a).unwrap(),
+            SignedDecimal::from_str(b).unwrap(),
+            SignedDecimal::from_str(c).unwrap(),
+        );
+        // The exact result in atomics is a * b / c over the atomics
+        let exact = a.abs_value().atomics().full_mul(b.abs_value().atomics());
+        let c_atomics = c.abs_value().atomics();
+        for (computed, max_error) in [
+            (
+                a.checked_mul(b).unwrap().checked_div(c).unwrap(),
+                bound.max_error().unwrap(),
+            ),
+            (
+                a.checked_div(c).unwrap().checked_mul(b).unwrap(),
+                bound.max_error_div_first().unwrap(),
+            ),
+        ] {
+            let computed = computed.abs_value().atomics().full_mul(c_atomics);
+            let diff = if computed > exact {
+                computed - exact
+            } else {
+                exact - computed
+            };
+            assert!(diff <= max_error.full_mul(c_atomics));
+        }
+    }
+}
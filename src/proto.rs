@@ -0,0 +1,187 @@
+//! Protobuf messages for exchanging signed values over gRPC, matching
+//!
+//! ```proto
+//! message SignedValue {
+//!   bool negative = 1;
+//!   bytes magnitude = 2;
+//! }
+//!
+//! message SignedString {
+//!   string value = 1;
+//! }
+//! ```
+//!
+//! `magnitude` is big-endian without leading zeros, as produced by Go's
+//! `big.Int.Bytes()`, and holds the atomics of a SignedDecimal.
+
+use std::{convert::TryFrom, str::FromStr};
+
+use cosmwasm_std::{Decimal256, Uint256};
+
+use crate::{
+    error::{CommonError, CommonResult},
+    signed_decimal::SignedDecimal,
+    signed_int::SignedInt,
+};
+
+/// Sign and magnitude form of a SignedInt or SignedDecimal
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct SignedValue {
+    #[prost(bool, tag = "1")]
+    pub negative: bool,
+    #[prost(bytes = "vec", tag = "2")]
+    pub magnitude: Vec<u8>,
+}
+
+/// Decimal string form of a SignedInt or SignedDecimal, e.g. "-1.5"
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct SignedString {
+    #[prost(string, tag = "1")]
+    pub value: String,
+}
+
+impl SignedValue {
+    fn new(negative: bool, magnitude: Uint256) -> Self {
+        let bytes = magnitude.to_be_bytes();
+        let start = bytes.iter().position(|b| *b != 0).unwrap_or(bytes.len());
+        Self {
+            negative,
+            magnitude: bytes[start..].to_vec(),
+        }
+    }
+
+    fn magnitude(&self) -> CommonResult<Uint256> {
+        if self.magnitude.len() > 32 {
+            return Err(CommonError::Generic(format!(
+                "Magnitude of {} bytes exceeds 256 bits",
+                self.magnitude.len()
+            )));
+        }
+        let mut bytes = [0u8; 32];
+        bytes[32 - self.magnitude.len()..].copy_from_slice(&self.magnitude);
+        Ok(Uint256::from_be_bytes(bytes))
+    }
+}
+
+impl From<SignedInt> for SignedValue {
+    fn from(value: SignedInt) -> Self {
+        Self::new(!value.is_positive, value.value)
+    }
+}
+
+impl TryFrom<SignedValue> for SignedInt {
+    type Error = CommonError;
+
+    fn try_from(proto: SignedValue) -> CommonResult<Self> {
+        Ok(Self {
+            value: proto.magnitude()?,
+            is_positive: !proto.negative,
+        })
+    }
+}
+
+impl From<SignedDecimal> for SignedValue {
+    fn from(value: SignedDecimal) -> Self {
+        Self::new(value.is_lt_zero(), value.abs_value().atomics())
+    }
+}
+
+impl TryFrom<SignedValue> for SignedDecimal {
+    type Error = CommonError;
+
+    fn try_from(proto: SignedValue) -> CommonResult<Self> {
+        let value = SignedDecimal::from(Decimal256::new(proto.magnitude()?));
+        Ok(if proto.negative { -value } else { value })
+    }
+}
+
+impl From<SignedInt> for SignedString {
+    fn from(value: SignedInt) -> Self {
+        Self {
+            value: value.to_string(),
+        }
+    }
+}
+
+impl TryFrom<SignedString> for SignedInt {
+    type Error = CommonError;
+
+    fn try_from(proto: SignedString) -> CommonResult<Self> {
+        Ok(Self::from_str(&proto.value)?)
+    }
+}
+
+impl From<SignedDecimal> for SignedString {
+    fn from(value: SignedDecimal) -> Self {
+        Self {
+            value: value.to_string(),
+        }
+    }
+}
+
+impl TryFrom<SignedString> for SignedDecimal {
+    type Error = CommonError;
+
+    fn try_from(proto: SignedString) -> CommonResult<Self> {
+        Ok(Self::from_str(&proto.value)?)
+    }
+}
+
+#[test]
+fn test_proto_conversions() {
+    use prost::Message;
+
+    let int = SignedInt::from_str("-258").unwrap();
+    let proto = SignedValue::from(int);
+    assert_eq!(
+        proto,
+        SignedValue {
+            negative: true,
+            magnitude: vec![1, 2],
+        }
+    );
+    let bytes = proto.encode_to_vec();
+    let decoded = SignedValue::decode(bytes.as_slice()).unwrap();
+    assert_eq!(SignedInt::try_from(decoded).unwrap(), int);
+
+    // Zero has an empty magnitude, which proto3 leaves out entirely
+    assert!(SignedValue::from(SignedInt::zero())
+        .encode_to_vec()
+        .is_empty());
+    assert_eq!(
+        SignedInt::try_from(SignedValue::default()).unwrap(),
+        SignedInt::zero()
+    );
+    assert_eq!(
+        SignedInt::try_from(SignedValue::from(SignedInt::MIN)).unwrap(),
+        SignedInt::MIN
+    );
+    assert!(SignedInt::try_from(SignedValue {
+        negative: false,
+        magnitude: vec![1; 33],
+    })
+    .is_err());
+
+    let dec = SignedDecimal::from_str("-1.5").unwrap();
+    let proto = SignedValue::from(dec);
+    assert_eq!(
+        proto.magnitude().unwrap(),
+        Uint256::from_u128(1_500_000_000_000_000_000)
+    );
+    assert_eq!(SignedDecimal::try_from(proto).unwrap(), dec);
+    let negative_zero = SignedValue {
+        negative: true,
+        magnitude: vec![],
+    };
+    let zero = SignedDecimal::try_from(negative_zero).unwrap();
+    assert!(zero.is_zero() && zero.is_gte_zero());
+
+    let proto = SignedString::from(dec);
+    assert_eq!(proto.value, "-1.5");
+    assert_eq!(SignedDecimal::try_from(proto).unwrap(), dec);
+    assert_eq!(SignedInt::try_from(SignedString::from(int)).unwrap(), int);
+    assert!(SignedInt::try_from(SignedString {
+        value: "1.5".to_string()
+    })
+    .is_err());
+}
@@ -0,0 +1,193 @@
+use std::{
+    collections::hash_map::DefaultHasher,
+    hash::{Hash, Hasher},
+    str::FromStr,
+};
+
+use pyo3::{
+    exceptions::{PyOverflowError, PyValueError, PyZeroDivisionError},
+    prelude::*,
+    pyclass::CompareOp,
+};
+
+use crate::{
+    error::{CommonError, CommonResult},
+    signed_decimal::SignedDecimal,
+    signed_int::SignedInt,
+};
+
+impl From<CommonError> for PyErr {
+    fn from(err: CommonError) -> Self {
+        match err {
+            CommonError::DivideByZero(_) => PyZeroDivisionError::new_err(err.to_string()),
+            CommonError::Overflow(_)
+            | CommonError::ConversionOverflow(_)
+            | CommonError::Decimal256RangeExceeded(_) => PyOverflowError::new_err(err.to_string()),
+            _ => PyValueError::new_err(err.to_string()),
+        }
+    }
+}
+
+fn hash_str(s: &str) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    s.hash(&mut hasher);
+    hasher.finish()
+}
+
+#[pymethods]
+impl SignedDecimal {
+    #[new]
+    fn py_new(value: &str) -> CommonResult<Self> {
+        Ok(Self::from_str(value)?)
+    }
+
+    fn __str__(&self) -> String {
+        self.to_string()
+    }
+
+    fn __repr__(&self) -> String {
+        format!("SignedDecimal('{}')", self.to_string())
+    }
+
+    fn __hash__(&self) -> u64 {
+        hash_str(&self.to_string())
+    }
+
+    fn __richcmp__(&self, other: Self, op: CompareOp) -> bool {
+        op.matches(self.cmp(&other))
+    }
+
+    fn __add__(&self, other: Self) -> CommonResult<Self> {
+        self.checked_add(other)
+    }
+
+    fn __sub__(&self, other: Self) -> CommonResult<Self> {
+        self.checked_sub(other)
+    }
+
+    fn __mul__(&self, other: Self) -> CommonResult<Self> {
+        self.checked_mul(other)
+    }
+
+    /// Raises ZeroDivisionError for a zero divisor, where the Rust operator
+    /// returns zero and would hide bugs in Python code
+    fn __truediv__(&self, other: Self) -> CommonResult<Self> {
+        self.checked_div(other)
+    }
+
+    fn __neg__(&self) -> Self {
+        -*self
+    }
+
+    fn __abs__(&self) -> CommonResult<Self> {
+        self.checked_abs()
+    }
+}
+
+#[pymethods]
+impl SignedInt {
+    #[new]
+    fn py_new(value: &str) -> CommonResult<Self> {
+        Ok(Self::from_str(value)?)
+    }
+
+    fn __str__(&self) -> String {
+        self.to_string()
+    }
+
+    fn __repr__(&self) -> String {
+        format!("SignedInt('{}')", self.to_string())
+    }
+
+    fn __hash__(&self) -> u64 {
+        hash_str(&self.to_string())
+    }
+
+    fn __richcmp__(&self, other: Self, op: CompareOp) -> bool {
+        op.matches(self.cmp(&other))
+    }
+
+    fn __add__(&self, other: Self) -> CommonResult<Self> {
+        self.checked_add(other)
+    }
+
+    fn __sub__(&self, other: Self) -> CommonResult<Self> {
+        self.checked_sub(other)
+    }
+
+    fn __mul__(&self, other: Self) -> CommonResult<Self> {
+        self.checked_mul(other)
+    }
+
+    /// Quotient truncated toward zero, unlike Python's `//`
+    fn div_trunc(&self, other: Self) -> CommonResult<Self> {
+        self.checked_div(other)
+    }
+
+    fn __pow__(&self, exp: u32, _modulo: Option<u32>) -> CommonResult<Self> {
+        self.checked_pow(exp)
+    }
+
+    fn __neg__(&self) -> Self {
+        -*self
+    }
+
+    fn __abs__(&self) -> CommonResult<Self> {
+        self.checked_abs()
+    }
+}
+
+/// Python module exposing both types, built with e.g.
+/// `maturin build --features python`
+#[pymodule]
+fn signed_decimal_prototype(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add_class::<SignedDecimal>()?;
+    m.add_class::<SignedInt>()?;
+    Ok(())
+}
+
+#[test]
+fn test_python_bindings() {
+    use pyo3::types::PyDict;
+
+    pyo3::prepare_freethreaded_python();
+    Python::with_gil(|py| {
+        let locals = PyDict::new_bound(py);
+        locals
+            .set_item("SignedDecimal", py.get_type_bound::<SignedDecimal>())
+            .unwrap();
+        locals
+            .set_item("SignedInt", py.get_type_bound::<SignedInt>())
+            .unwrap();
+        let run = |code: &str| py.run_bound(code, None, Some(&locals)).unwrap();
+        let fails = |code: &str, exception: &str| {
+            let err = py.run_bound(code, None, Some(&locals)).unwrap_err();
+            assert_eq!(err.get_type_bound(py).name().unwrap(), exception);
+        };
+
+        run("x = SignedDecimal('-1.5') * SignedDecimal('2') + SignedDecimal('0.25')");
+        run("assert str(x) == '-2.75'");
+        run("assert repr(-x) == \"SignedDecimal('2.75')\"");
+        run("assert x < SignedDecimal('0') and abs(x) > SignedDecimal('2')");
+        run("assert x == SignedDecimal('-2.750') and hash(x) == hash(SignedDecimal('-2.75'))");
+        run("assert str(SignedDecimal('1') / SignedDecimal('-4')) == '-0.25'");
+        fails(
+            "SignedDecimal('1') / SignedDecimal('0')",
+            "ZeroDivisionError",
+        );
+        fails("SignedDecimal('1.2.3')", "ValueError");
+        run("big = SignedDecimal('100000000000000000000000000000000000000000')");
+        fails("big * big", "OverflowError");
+        run("low = SignedDecimal('-1' + '0' * 59)");
+        fails("low - (-low)", "OverflowError");
+
+        run("n = SignedInt('-7') * SignedInt('3') - SignedInt('1')");
+        run("assert str(n) == '-22' and n.div_trunc(SignedInt('5')) == SignedInt('-4')");
+        run("assert SignedInt('-2') ** 3 == SignedInt('-8')");
+        fails(
+            "SignedInt('7').div_trunc(SignedInt('0'))",
+            "ZeroDivisionError",
+        );
+        fails("SignedInt('2') ** 256", "OverflowError");
+    });
+}
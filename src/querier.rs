@@ -0,0 +1,103 @@
+use cosmwasm_std::{CustomQuery, Decimal256, QuerierWrapper};
+use serde::{Deserialize, Serialize};
+
+use crate::{error::CommonResult, response::SignedDecimalResponse, signed_decimal::SignedDecimal};
+
+/// The shapes other contracts use to return a signed decimal
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum SignedDecimalWire {
+    /// `"-1.5"`
+    Str(SignedDecimal),
+    /// `{"value": "1.5", "is_positive": false}`
+    IsPositive {
+        value: Decimal256,
+        is_positive: bool,
+    },
+    /// `{"value": "1.5", "negative": true}`
+    Negative { value: Decimal256, negative: bool },
+    /// `{"value": "-1.5"}`, see [`SignedDecimalResponse`]
+    Response(SignedDecimalResponse),
+}
+
+impl From<SignedDecimalWire> for SignedDecimal {
+    fn from(wire: SignedDecimalWire) -> Self {
+        let (value, negative) = match wire {
+            SignedDecimalWire::Str(d) => return d,
+            SignedDecimalWire::Response(response) => return response.value,
+            SignedDecimalWire::IsPositive { value, is_positive } => (value, !is_positive),
+            SignedDecimalWire::Negative { value, negative } => (value, negative),
+        };
+        if negative {
+            -SignedDecimal::from(value)
+        } else {
+            SignedDecimal::from(value)
+        }
+    }
+}
+
+pub trait QuerierExt {
+    /// Smart queries `contract` and parses the response as a signed decimal,
+    /// accepting either a plain string, a `{value, is_positive}` /
+    /// `{value, negative}` struct or a [`SignedDecimalResponse`]
+    fn query_signed_decimal(
+        &self,
+        contract: impl Into<String>,
+        msg: &impl Serialize,
+    ) -> CommonResult<SignedDecimal>;
+}
+
+impl<C: CustomQuery> QuerierExt for QuerierWrapper<'_, C> {
+    fn query_signed_decimal(
+        &self,
+        contract: impl Into<String>,
+        msg: &impl Serialize,
+    ) -> CommonResult<SignedDecimal> {
+        let wire: SignedDecimalWire = self.query_wasm_smart(contract, msg)?;
+        Ok(wire.into())
+    }
+}
+
+#[test]
+fn test_query_signed_decimal() {
+    use std::str::FromStr;
+
+    use cosmwasm_std::{
+        testing::MockQuerier, Binary, ContractResult, Empty, SystemResult, WasmQuery,
+    };
+
+    let mut querier: MockQuerier = MockQuerier::new(&[]);
+    querier.update_wasm(|query| {
+        let response: &[u8] = match query {
+            WasmQuery::Smart { contract_addr, .. } => match contract_addr.as_str() {
+                "string" => br#""-1.5""#,
+                "is_positive" => br#"{"value":"1.5","is_positive":false}"#,
+                "negative" => br#"{"value":"2.25","negative":false}"#,
+                "response" => br#"{"value":"-0.5","height":10}"#,
+                _ => br#"{"price":"1"}"#,
+            },
+            _ => panic!("unexpected query {query:?}"),
+        };
+        SystemResult::Ok(ContractResult::Ok(Binary::from(response)))
+    });
+    let wrapper = QuerierWrapper::<Empty>::new(&querier);
+    let msg = Empty {};
+
+    assert_eq!(
+        wrapper.query_signed_decimal("string", &msg).unwrap(),
+        SignedDecimal::from_str("-1.5").unwrap()
+    );
+    assert_eq!(
+        wrapper.query_signed_decimal("is_positive", &msg).unwrap(),
+        SignedDecimal::from_str("-1.5").unwrap()
+    );
+    assert_eq!(
+        wrapper.query_signed_decimal("negative", &msg).unwrap(),
+        SignedDecimal::from_str("2.25").unwrap()
+    );
+    assert_eq!(
+        wrapper.query_signed_decimal("response", &msg).unwrap(),
+        SignedDecimal::from_str("-0.5").unwrap()
+    );
+    assert!(wrapper.query_signed_decimal("other", &msg).is_err());
+}
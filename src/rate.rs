@@ -0,0 +1,251 @@
+use cosmwasm_std::{Decimal256, DivideByZeroError, Timestamp, Uint256, Uint512};
+
+use crate::{
+    error::{CommonError, CommonResult},
+    signed_decimal::{RoundingMode, SignedDecimal},
+};
+
+/// Seconds in a 365 day year
+pub const SECONDS_PER_YEAR: u64 = 31_536_000;
+
+/// Converts an annual rate into a per-second rate, truncating toward zero.
+///
+/// Prefer [`accrue`] for computing interest over a period, since multiplying
+/// the truncated per-second rate back up loses precision.
+pub fn per_second_rate(rate_per_year: SignedDecimal) -> SignedDecimal {
+    let value = rate_per_year.abs_value() / Uint256::from(SECONDS_PER_YEAR);
+    with_sign_of(rate_per_year, value)
+}
+
+/// Simple interest accrued at the annual `rate` between `from` and `to`, i.e.
+/// `rate * (to - from) / SECONDS_PER_YEAR` truncated toward zero.
+///
+/// The product is formed in 512 bits before dividing, so the result is exact
+/// up to the final truncation. Errors if `to` precedes `from`.
+pub fn accrue(rate: SignedDecimal, from: Timestamp, to: Timestamp) -> CommonResult<SignedDecimal> {
+    let elapsed = to.seconds().checked_sub(from.seconds()).ok_or_else(|| {
+        CommonError::Generic(format!(
+            "Accrual end {} precedes start {}",
+            to.seconds(),
+            from.seconds()
+        ))
+    })?;
+    let atomics = rate.abs_value().atomics().full_mul(elapsed) / Uint512::from(SECONDS_PER_YEAR);
+    let value = Decimal256::new(Uint256::try_from(atomics)?);
+    Ok(with_sign_of(rate, value))
+}
+
+/// Share of `period` that `elapsed` covers, truncated and capped at one, as
+/// used for vesting schedules. An empty period counts as complete.
+pub fn fraction_of_period(elapsed: u64, period: u64) -> Decimal256 {
+    if elapsed >= period {
+        return Decimal256::one();
+    }
+    Decimal256::from_ratio(elapsed, period)
+}
+
+/// Value `elapsed` of the way through `period` on the line from `start` to
+/// `end`, capped at `end`.
+///
+/// The step from `start` is computed exactly and truncated toward zero, so
+/// the result never passes the exact interpolation.
+pub fn interpolate_signed(
+    start: SignedDecimal,
+    end: SignedDecimal,
+    elapsed: u64,
+    period: u64,
+) -> CommonResult<SignedDecimal> {
+    if elapsed >= period {
+        return Ok(end);
+    }
+    let delta = end.checked_sub(start)?;
+    let atomics = RoundingMode::TowardZero.div(
+        delta.abs_value().atomics().full_mul(elapsed),
+        Uint512::from(period),
+        delta.is_gte_zero(),
+    )?;
+    start.checked_add(with_sign_of(delta, Decimal256::new(atomics)))
+}
+
+/// Relative change `(a - b) / b` of the unsigned price `a` against `b`,
+/// e.g. a return or premium, truncated toward zero
+pub fn signed_ratio(a: Decimal256, b: Decimal256) -> CommonResult<SignedDecimal> {
+    if b.is_zero() {
+        return Err(DivideByZeroError::new(a).into());
+    }
+    let (diff, is_positive) = if a >= b {
+        (a - b, true)
+    } else {
+        (b - a, false)
+    };
+    let atomics = RoundingMode::TowardZero.div(
+        diff.atomics()
+            .full_mul(10u128.pow(SignedDecimal::DECIMAL_PLACES)),
+        b.atomics().into(),
+        is_positive,
+    )?;
+    let value = SignedDecimal::from(Decimal256::new(atomics));
+    Ok(if is_positive { value } else { -value })
+}
+
+/// `value * (1 - rate_per_period)^periods`, e.g. an imbalance decaying by
+/// 1% per block, truncated toward zero. Errors if the rate exceeds one.
+///
+/// The factor is raised by squaring in 18 decimal fixed point, truncating
+/// each product, so it falls short of the exact power by less than `periods`
+/// units of 10^-18, and usually by a few. The result never exceeds the exact
+/// decay in magnitude.
+pub fn decay(
+    value: SignedDecimal,
+    rate_per_period: Decimal256,
+    periods: u64,
+) -> CommonResult<SignedDecimal> {
+    if rate_per_period > Decimal256::one() {
+        return Err(CommonError::Generic(format!(
+            "Decay rate {rate_per_period} exceeds one"
+        )));
+    }
+    let one = Decimal256::one().atomics();
+    // Both factors are at most one, so the products fit in 256 bits
+    let mul = |a: Uint256, b: Uint256| a * b / one;
+    let mut base = one - rate_per_period.atomics();
+    let mut factor = one;
+    let mut n = periods;
+    while n > 0 && !factor.is_zero() {
+        if n & 1 == 1 {
+            factor = mul(factor, base);
+        }
+        n >>= 1;
+        if n > 0 {
+            base = mul(base, base);
+        }
+    }
+    let atomics = RoundingMode::TowardZero.div(
+        value.abs_value().atomics().full_mul(factor),
+        one.into(),
+        value.is_gte_zero(),
+    )?;
+    Ok(with_sign_of(value, Decimal256::new(atomics)))
+}
+
+fn with_sign_of(sign: SignedDecimal, value: Decimal256) -> SignedDecimal {
+    let value = SignedDecimal::from(value);
+    if sign.is_lt_zero() {
+        -value
+    } else {
+        value
+    }
+}
+
+#[test]
+fn test_accrue() {
+    use std::str::FromStr;
+
+    let dec = |s: &str| SignedDecimal::from_str(s).unwrap();
+    let start = Timestamp::from_seconds(1_600_000_000);
+
+    assert_eq!(per_second_rate(dec("31.536")), dec("0.000001"));
+    assert_eq!(per_second_rate(dec("-31.536")), dec("-0.000001"));
+    assert_eq!(per_second_rate(dec("0.05")), dec("0.000000001585489599"));
+
+    let half_year = start.plus_seconds(SECONDS_PER_YEAR / 2);
+    assert_eq!(accrue(dec("0.1"), start, half_year).unwrap(), dec("0.05"));
+    assert_eq!(accrue(dec("-0.1"), start, half_year).unwrap(), dec("-0.05"));
+    assert_eq!(accrue(dec("0.1"), start, start).unwrap(), dec("0"));
+
+    // Keeps the digits a truncated per-second rate times 86400 would lose
+    let one_day = start.plus_seconds(86_400);
+    assert_eq!(
+        accrue(dec("0.05"), start, one_day).unwrap(),
+        dec("0.000136986301369863")
+    );
+
+    assert!(accrue(dec("0.1"), half_year, start).is_err());
+}
+
+#[test]
+fn test_interpolate_signed() {
+    use std::str::FromStr;
+
+    let dec = |s: &str| SignedDecimal::from_str(s).unwrap();
+
+    assert_eq!(fraction_of_period(250, 1000), Decimal256::percent(25));
+    assert_eq!(
+        fraction_of_period(1, 3),
+        Decimal256::from_str("0.333333333333333333").unwrap()
+    );
+    assert_eq!(fraction_of_period(2000, 1000), Decimal256::one());
+    assert_eq!(fraction_of_period(0, 0), Decimal256::one());
+
+    assert_eq!(
+        interpolate_signed(dec("-1"), dec("3"), 250, 1000).unwrap(),
+        dec("0")
+    );
+    assert_eq!(
+        interpolate_signed(dec("1"), dec("-1"), 1, 3).unwrap(),
+        dec("0.333333333333333334")
+    );
+    assert_eq!(
+        interpolate_signed(dec("-1"), dec("1"), 1, 3).unwrap(),
+        dec("-0.333333333333333334")
+    );
+    assert_eq!(
+        interpolate_signed(dec("1"), dec("-1"), 5, 3).unwrap(),
+        dec("-1")
+    );
+    assert_eq!(
+        interpolate_signed(dec("2"), dec("2"), 1, 2).unwrap(),
+        dec("2")
+    );
+}
+
+#[test]
+fn test_signed_ratio() {
+    use std::str::FromStr;
+
+    let dec = |s: &str| Decimal256::from_str(s).unwrap();
+    let signed = |s: &str| SignedDecimal::from_str(s).unwrap();
+
+    assert_eq!(signed_ratio(dec("110"), dec("100")).unwrap(), signed("0.1"));
+    assert_eq!(signed_ratio(dec("90"), dec("100")).unwrap(), signed("-0.1"));
+    assert_eq!(signed_ratio(dec("1.5"), dec("1.5")).unwrap(), signed("0"));
+    assert_eq!(
+        signed_ratio(dec("1"), dec("3")).unwrap(),
+        signed("-0.666666666666666666")
+    );
+    assert!(signed_ratio(dec("1"), dec("0")).is_err());
+    assert!(signed_ratio(Decimal256::MAX, dec("0.5")).is_err());
+}
+
+#[test]
+fn test_decay() {
+    use std::str::FromStr;
+
+    let dec = |s: &str| SignedDecimal::from_str(s).unwrap();
+    let rate = |s: &str| Decimal256::from_str(s).unwrap();
+
+    assert_eq!(decay(dec("-100"), rate("0.5"), 3).unwrap(), dec("-12.5"));
+    assert_eq!(
+        decay(dec("1"), rate("0.1"), 10).unwrap(),
+        dec("0.3486784401")
+    );
+    assert_eq!(decay(dec("-7"), rate("0.3"), 0).unwrap(), dec("-7"));
+    assert_eq!(decay(dec("-7"), rate("0"), u64::MAX).unwrap(), dec("-7"));
+    assert_eq!(decay(dec("-7"), rate("1"), 1).unwrap(), dec("0"));
+    assert_eq!(
+        decay(SignedDecimal::MIN, rate("0.01"), u64::MAX).unwrap(),
+        dec("0")
+    );
+
+    // Exactly 1 - 1000 * 10^-18 once truncated
+    assert_eq!(
+        decay(dec("1"), rate("0.000000000000000001"), 1000).unwrap(),
+        dec("0.999999999999999")
+    );
+    // The exact result is about -2.46 * 10^-18, and truncation only moves it
+    // toward zero
+    let decayed = decay(dec("-1"), rate("0.333333333333333333"), 100).unwrap();
+    assert!(decayed > dec("-0.000000000000000003") && decayed <= dec("0"));
+
+    assert!(decay(dec("1"), rate("1.5"), 1).is_err());
+}
@@ -0,0 +1,101 @@
+//! Standard query responses for contracts exposing a single signed value,
+//! so clients can share one shape:
+//!
+//! ```json
+//! {"value": "-1.5", "height": 1234}
+//! ```
+
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::{signed_decimal::SignedDecimal, signed_int::SignedInt};
+
+/// A SignedDecimal query result with optional context, which is left out
+/// of the JSON when unset
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct SignedDecimalResponse {
+    pub value: SignedDecimal,
+    /// Block height the value was read at
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub height: Option<u64>,
+    /// Denom or unit the value is measured in
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub denom: Option<String>,
+}
+
+/// A SignedInt query result with optional context, which is left out of the
+/// JSON when unset
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct SignedIntResponse {
+    pub value: SignedInt,
+    /// Block height the value was read at
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub height: Option<u64>,
+    /// Denom or unit the value is measured in
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub denom: Option<String>,
+}
+
+impl From<SignedDecimal> for SignedDecimalResponse {
+    fn from(value: SignedDecimal) -> Self {
+        Self {
+            value,
+            height: None,
+            denom: None,
+        }
+    }
+}
+
+impl From<SignedInt> for SignedIntResponse {
+    fn from(value: SignedInt) -> Self {
+        Self {
+            value,
+            height: None,
+            denom: None,
+        }
+    }
+}
+
+#[test]
+fn test_responses() {
+    use std::str::FromStr;
+
+    let dec = SignedDecimal::from_str("-1.5").unwrap();
+    let response = SignedDecimalResponse::from(dec);
+    assert_eq!(
+        serde_json::to_string(&response).unwrap(),
+        r#"{"value":"-1.5"}"#
+    );
+    let response = SignedDecimalResponse {
+        height: Some(1234),
+        ..response
+    };
+    let json = serde_json::to_string(&response).unwrap();
+    assert_eq!(json, r#"{"value":"-1.5","height":1234}"#);
+    assert_eq!(
+        serde_json::from_str::<SignedDecimalResponse>(&json).unwrap(),
+        response
+    );
+
+    let int = SignedIntResponse {
+        denom: Some("uatom".to_string()),
+        ..SignedIntResponse::from(SignedInt::from(-7i8))
+    };
+    let json = serde_json::to_string(&int).unwrap();
+    assert_eq!(
+        serde_json::from_str::<SignedIntResponse>(&json).unwrap(),
+        int
+    );
+
+    let schema = schemars::schema_for!(SignedDecimalResponse);
+    assert_eq!(
+        schema
+            .schema
+            .object
+            .unwrap()
+            .required
+            .into_iter()
+            .collect::<Vec<_>>(),
+        vec!["value".to_string()]
+    );
+}
@@ -0,0 +1,120 @@
+use cosmwasm_std::{Decimal256, Uint256};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    error::CommonResult,
+    signed_decimal::{RoundingMode, SignedDecimal},
+    signed_int::SignedInt,
+};
+
+/// Cumulative rewards per unit of stake, which may fall when stakers are
+/// slashed. Each staker stores a copy of the index when their stake changes,
+/// and [`RewardIndex::pending`] gives what they accrued since then.
+///
+/// Products are formed in 512 bits before scaling back down, and every
+/// division rounds toward negative infinity, so rounding never pays out more
+/// or slashes less than was distributed.
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, Default, PartialEq, Eq, JsonSchema)]
+pub struct RewardIndex(pub SignedDecimal);
+
+impl RewardIndex {
+    /// Spreads `rewards` over `total_stake`, negative rewards being a slash
+    pub fn distribute(&mut self, rewards: SignedInt, total_stake: Uint256) -> CommonResult<()> {
+        let per_share = RoundingMode::Floor.div(
+            rewards.value.full_mul(Decimal256::one().atomics()),
+            total_stake.into(),
+            rewards.is_positive,
+        )?;
+        let per_share = SignedDecimal::from(Decimal256::new(per_share));
+        self.0 = self.0.checked_add(if rewards.is_positive {
+            per_share
+        } else {
+            -per_share
+        })?;
+        Ok(())
+    }
+
+    /// Rewards accrued by `user_stake` since the user recorded `user_index`
+    pub fn pending(
+        &self,
+        user_stake: Uint256,
+        user_index: &RewardIndex,
+    ) -> CommonResult<SignedInt> {
+        let delta = self.0.checked_sub(user_index.0)?;
+        let value = RoundingMode::Floor.div(
+            user_stake.full_mul(delta.abs_value().atomics()),
+            Decimal256::one().atomics().into(),
+            delta.is_gte_zero(),
+        )?;
+        Ok(SignedInt {
+            value,
+            is_positive: delta.is_gte_zero() || value.is_zero(),
+        })
+    }
+}
+
+#[test]
+fn test_reward_index() {
+    use std::str::FromStr;
+
+    let int = |s: &str| SignedInt::from_str(s).unwrap();
+    let mut index = RewardIndex::default();
+    let alice_index = index;
+
+    // 100 rewards over 300 stake: alice holds 200, bob joins later
+    index
+        .distribute(int("100"), Uint256::from_u128(300))
+        .unwrap();
+    assert_eq!(index.0.to_string(), "0.333333333333333333");
+    let bob_index = index;
+    assert_eq!(
+        index
+            .pending(Uint256::from_u128(200), &alice_index)
+            .unwrap(),
+        int("66")
+    );
+
+    // A slash of 30 over the same 300 stake
+    index
+        .distribute(int("-30"), Uint256::from_u128(300))
+        .unwrap();
+    assert_eq!(
+        index
+            .pending(Uint256::from_u128(200), &alice_index)
+            .unwrap(),
+        int("46")
+    );
+    assert_eq!(
+        index.pending(Uint256::from_u128(100), &bob_index).unwrap(),
+        int("-10")
+    );
+
+    // A slash rounds toward negative infinity: -10 over 3 stake
+    let mut index = RewardIndex::default();
+    index.distribute(int("-10"), Uint256::from_u128(3)).unwrap();
+    assert_eq!(index.0.to_string(), "-3.333333333333333334");
+    assert_eq!(
+        index
+            .pending(Uint256::from_u128(3), &RewardIndex::default())
+            .unwrap(),
+        int("-11")
+    );
+
+    // Large reward amounts don't overflow the intermediate product
+    let mut index = RewardIndex::default();
+    let huge = SignedInt {
+        value: Uint256::MAX / Uint256::from_u128(1_000_000_000_000_000_000_000),
+        is_positive: true,
+    };
+    index.distribute(huge, Uint256::from_u128(10)).unwrap();
+    assert!(index.distribute(huge, Uint256::zero()).is_err());
+
+    // An index that would leave the SignedDecimal range is left unchanged
+    let mut index = RewardIndex(SignedDecimal::MAX);
+    assert!(index.distribute(int("1"), Uint256::from_u128(1)).is_err());
+    assert_eq!(index, RewardIndex(SignedDecimal::MAX));
+    assert!(index
+        .pending(Uint256::from_u128(1), &RewardIndex(SignedDecimal::MIN))
+        .is_err());
+}
@@ -0,0 +1,182 @@
+use serde::{de, Deserialize, Deserializer};
+
+use crate::error::CommonError;
+
+/// Deserializes a value and rejects it unless `min <= value <= max`.
+///
+/// `#[serde(deserialize_with = ...)]` only accepts the deserializer, so bind
+/// the bounds in a small wrapper:
+///
+/// ```ignore
+/// fn funding_cap<'de, D: Deserializer<'de>>(d: D) -> Result<SignedDecimal, D::Error> {
+///     deserialize_bounded(d, SignedDecimal::from_str("-0.1").unwrap(), SignedDecimal::from_str("0.1").unwrap())
+/// }
+/// ```
+pub fn deserialize_bounded<'de, D, T>(deserializer: D, min: T, max: T) -> Result<T, D::Error>
+where
+    D: Deserializer<'de>,
+    T: Deserialize<'de> + PartialOrd + ToString,
+{
+    let value = T::deserialize(deserializer)?;
+    if value < min || value > max {
+        let err = CommonError::OutOfRange {
+            value: value.to_string(),
+            min: min.to_string(),
+            max: max.to_string(),
+        };
+        return Err(de::Error::custom(err));
+    }
+    Ok(value)
+}
+
+/// Deserializes a [`SignedDecimal`] from a decimal string or a JSON number,
+/// for payloads from services that send numbers. Use it with
+/// `#[serde(deserialize_with = "serde_helpers::deserialize_json_number")]`.
+///
+/// Only JSON integers are accepted unless the `arbitrary_precision` feature
+/// keeps other numbers exact, see `TryFrom<serde_json::Number>`.
+///
+/// [`SignedDecimal`]: crate::signed_decimal::SignedDecimal
+#[cfg(feature = "json")]
+pub fn deserialize_json_number<'de, D>(
+    deserializer: D,
+) -> Result<crate::signed_decimal::SignedDecimal, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    use std::{convert::TryFrom, str::FromStr};
+
+    use serde_json::Value;
+
+    use crate::signed_decimal::SignedDecimal;
+
+    match Value::deserialize(deserializer)? {
+        Value::String(s) => SignedDecimal::from_str(&s).map_err(de::Error::custom),
+        Value::Number(n) => SignedDecimal::try_from(n).map_err(de::Error::custom),
+        other => Err(de::Error::custom(format!(
+            "invalid type: {other}, expected a decimal string or number"
+        ))),
+    }
+}
+
+/// (De)serializes a `BTreeMap` keyed by [`SignedInt`] or [`SignedDecimal`] as a
+/// JSON object, using the canonical string form of each key. Use it with
+/// `#[serde(with = "serde_helpers::signed_map")]`.
+///
+/// Keys that parse to the same value, such as `"0"` and `"-0"`, are rejected
+/// rather than silently overwriting each other.
+///
+/// [`SignedInt`]: crate::signed_int::SignedInt
+/// [`SignedDecimal`]: crate::signed_decimal::SignedDecimal
+pub mod signed_map {
+    use std::{collections::BTreeMap, fmt::Display, str::FromStr};
+
+    use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
+
+    pub fn serialize<S, K, V>(map: &BTreeMap<K, V>, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+        K: ToString,
+        V: Serialize,
+    {
+        serializer.collect_map(map.iter().map(|(k, v)| (k.to_string(), v)))
+    }
+
+    pub fn deserialize<'de, D, K, V>(deserializer: D) -> Result<BTreeMap<K, V>, D::Error>
+    where
+        D: Deserializer<'de>,
+        K: FromStr + Ord,
+        K::Err: Display,
+        V: Deserialize<'de>,
+    {
+        let raw = BTreeMap::<String, V>::deserialize(deserializer)?;
+        let mut map = BTreeMap::new();
+        for (key, value) in raw {
+            let parsed = K::from_str(&key).map_err(de::Error::custom)?;
+            if map.insert(parsed, value).is_some() {
+                return Err(de::Error::custom(format!("duplicate key {key}")));
+            }
+        }
+        Ok(map)
+    }
+}
+
+#[test]
+fn test_deserialize_bounded() {
+    use std::str::FromStr;
+
+    use crate::{signed_decimal::SignedDecimal, signed_int::SignedInt};
+
+    fn skew<'de, D: Deserializer<'de>>(d: D) -> Result<SignedDecimal, D::Error> {
+        deserialize_bounded(
+            d,
+            SignedDecimal::from_str("-0.5").unwrap(),
+            SignedDecimal::from_str("0.5").unwrap(),
+        )
+    }
+
+    fn delta<'de, D: Deserializer<'de>>(d: D) -> Result<SignedInt, D::Error> {
+        deserialize_bounded(d, SignedInt::from_str("-10").unwrap(), SignedInt::zero())
+    }
+
+    #[derive(Deserialize, Debug)]
+    struct Params {
+        #[serde(deserialize_with = "skew")]
+        skew: SignedDecimal,
+        #[serde(deserialize_with = "delta")]
+        delta: SignedInt,
+    }
+
+    let params: Params =
+        serde_json::from_str(r#"{"skew":"-0.5","delta":{"value":"10","is_positive":false}}"#)
+            .unwrap();
+    assert_eq!(params.skew, SignedDecimal::from_str("-0.5").unwrap());
+    assert_eq!(params.delta, SignedInt::from_str("-10").unwrap());
+
+    let err = serde_json::from_str::<Params>(
+        r#"{"skew":"0.6","delta":{"value":"1","is_positive":false}}"#,
+    )
+    .unwrap_err();
+    assert!(err
+        .to_string()
+        .contains("0.6 is outside the allowed range [-0.5, 0.5]"));
+
+    assert!(serde_json::from_str::<Params>(
+        r#"{"skew":"0","delta":{"value":"1","is_positive":true}}"#
+    )
+    .is_err());
+}
+
+#[test]
+fn test_signed_map() {
+    use std::{collections::BTreeMap, str::FromStr};
+
+    use serde::Serialize;
+
+    use crate::signed_int::SignedInt;
+
+    #[derive(Serialize, Deserialize, Debug, PartialEq)]
+    struct Ticks {
+        #[serde(with = "signed_map")]
+        liquidity: BTreeMap<SignedInt, u64>,
+    }
+
+    let ticks = Ticks {
+        liquidity: BTreeMap::from([
+            (SignedInt::from_str("-20").unwrap(), 1),
+            (SignedInt::zero(), 2),
+            (SignedInt::from_str("15").unwrap(), 3),
+        ]),
+    };
+    let json = serde_json::to_string(&ticks).unwrap();
+    assert_eq!(json, r#"{"liquidity":{"-20":1,"0":2,"15":3}}"#);
+    assert_eq!(serde_json::from_str::<Ticks>(&json).unwrap(), ticks);
+
+    // keys are ordered by value, not by their string form
+    let keys: Vec<_> = ticks.liquidity.keys().map(|k| k.to_string()).collect();
+    assert_eq!(keys, ["-20", "0", "15"]);
+
+    assert!(serde_json::from_str::<Ticks>(r#"{"liquidity":{"abc":1}}"#).is_err());
+    let err = serde_json::from_str::<Ticks>(r#"{"liquidity":{"0":1,"-0":2}}"#).unwrap_err();
+    assert!(err.to_string().contains("duplicate key"));
+}
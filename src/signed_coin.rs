@@ -0,0 +1,79 @@
+use cosmwasm_std::{BankMsg, Coin, CosmosMsg, Uint128};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    error::{CommonError, CommonResult},
+    signed_int::SignedInt,
+};
+
+/// Coin with a signed amount, e.g. a settlement delta owed to or by an account
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct SignedCoin {
+    pub denom: String,
+    pub amount: SignedInt,
+}
+
+impl SignedCoin {
+    pub fn new(amount: SignedInt, denom: impl Into<String>) -> Self {
+        Self {
+            denom: denom.into(),
+            amount,
+        }
+    }
+}
+
+/// Turns a settlement delta for `addr` into a message.
+///
+/// A positive amount is paid out with a `BankMsg::Send`, a zero amount needs
+/// no message, and a negative amount can't be realized by the contract so it
+/// errors with `CommonError::RequiredDeposit` describing what `addr` owes.
+pub fn settle(addr: impl Into<String>, coin: SignedCoin) -> CommonResult<Option<CosmosMsg>> {
+    if coin.amount.is_zero() {
+        return Ok(None);
+    }
+    let amount = Coin {
+        denom: coin.denom,
+        amount: Uint128::try_from(coin.amount.value)?,
+    };
+    if !coin.amount.is_positive {
+        return Err(CommonError::RequiredDeposit {
+            address: addr.into(),
+            amount,
+        });
+    }
+    Ok(Some(
+        BankMsg::Send {
+            to_address: addr.into(),
+            amount: vec![amount],
+        }
+        .into(),
+    ))
+}
+
+#[test]
+fn test_settle() {
+    use std::str::FromStr;
+
+    let coin = |amount: &str| SignedCoin::new(SignedInt::from_str(amount).unwrap(), "uatom");
+
+    assert_eq!(settle("trader", coin("0")).unwrap(), None);
+    assert_eq!(
+        settle("trader", coin("250")).unwrap(),
+        Some(CosmosMsg::Bank(BankMsg::Send {
+            to_address: "trader".into(),
+            amount: vec![Coin::new(250, "uatom")],
+        }))
+    );
+    assert_eq!(
+        settle("trader", coin("-250")),
+        Err(CommonError::RequiredDeposit {
+            address: "trader".into(),
+            amount: Coin::new(250, "uatom"),
+        })
+    );
+    assert!(matches!(
+        settle("trader", SignedCoin::new(SignedInt::MAX, "uatom")),
+        Err(CommonError::ConversionOverflow(_))
+    ));
+}
@@ -1,56 +1,787 @@
 use std::{
+    cmp::Ordering,
     convert::{TryFrom, TryInto},
     fmt,
-    ops::{Mul, Neg, Rem},
+    ops::{Neg, RangeBounds},
     str::FromStr,
 };
 
-use cosmwasm_std::{Decimal256, StdError, Uint256};
+use cosmwasm_std::{
+    ConversionOverflowError, Decimal256, DivideByZeroError, OverflowError, OverflowOperation,
+    Uint128, Uint256, Uint512,
+};
 pub use num_traits::*;
-use num_traits::{Num, One, Zero};
 use schemars::JsonSchema;
 use serde::{de, ser, Deserialize, Deserializer, Serialize};
 
-use crate::{error::CommonError, signed_int::SignedInt};
+use crate::{
+    error::{CommonError, CommonResult, ParseSignedError},
+    signed_int::{shift_pow10, SignedInt},
+};
+
+/// How to round a value that falls between two representable results
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum RoundingMode {
+    /// Toward negative infinity
+    Floor,
+    /// Toward positive infinity
+    Ceil,
+    /// Toward zero
+    TowardZero,
+    /// Away from zero
+    AwayFromZero,
+    /// To the nearest result, ties away from zero
+    HalfUp,
+}
+
+impl RoundingMode {
+    /// Divides the magnitudes `numerator / denominator` and rounds the
+    /// quotient, where `is_positive` is the sign of the signed result
+    pub(crate) fn div(
+        self,
+        numerator: Uint512,
+        denominator: Uint512,
+        is_positive: bool,
+    ) -> CommonResult<Uint256> {
+        let quotient = numerator
+            .checked_div(denominator)
+            .map_err(|_| DivideByZeroError::new(numerator))?;
+        let remainder = numerator - quotient * denominator;
+        let away_from_zero = !remainder.is_zero()
+            && match self {
+                RoundingMode::Floor => !is_positive,
+                RoundingMode::Ceil => is_positive,
+                RoundingMode::TowardZero => false,
+                RoundingMode::AwayFromZero => true,
+                RoundingMode::HalfUp => remainder >= denominator - remainder,
+            };
+        let quotient = Uint256::try_from(quotient)?;
+        Ok(if away_from_zero {
+            quotient.checked_add(Uint256::from_u128(1))?
+        } else {
+            quotient
+        })
+    }
+}
+
+/// How a SignedInt is read when combined with a SignedDecimal
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum IntScale {
+    /// A whole number, so 3 is 3.0
+    Whole,
+    /// Atomics of the decimal, so 3 is 0.000000000000000003
+    Atomics,
+}
 
 /// Decimal256 with a sign
 #[derive(Clone, Copy, Debug, Eq)]
+#[cfg_attr(feature = "python", pyo3::pyclass(module = "signed_decimal_prototype"))]
 pub struct SignedDecimal {
     value: Decimal256,
     is_positive: bool,
 }
 
 impl SignedDecimal {
+    /// Number of fractional digits, matching Decimal256
+    pub const DECIMAL_PLACES: u32 = 18;
+
+    pub const MAX: Self = Self {
+        value: Decimal256::MAX,
+        is_positive: true,
+    };
+
+    pub const MIN: Self = Self {
+        value: Decimal256::MAX,
+        is_positive: false,
+    };
+
+    pub const fn zero() -> Self {
+        Self {
+            value: Decimal256::zero(),
+            is_positive: true,
+        }
+    }
+
+    pub const fn one() -> Self {
+        Self {
+            value: Decimal256::one(),
+            is_positive: true,
+        }
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.value.is_zero()
+    }
+
+    /// The value as a Decimal256, panicking if it is negative
+    #[cfg(not(feature = "no-panic"))]
     pub fn value(&self) -> Decimal256 {
         assert!(self.is_positive, "SignedDecimal is negative!");
         self.value
     }
 
+    /// The value as a Decimal256, or an error if it is negative
+    pub fn try_value(&self) -> CommonResult<Decimal256> {
+        (*self).try_into()
+    }
+
+    /// The magnitude as a Decimal256, dropping the sign
+    pub fn abs_value(&self) -> Decimal256 {
+        self.value
+    }
+
+    /// The magnitude and whether self is non-negative, the inverse of
+    /// `From<(Decimal256, bool)>`
+    pub const fn as_tuple(&self) -> (Decimal256, bool) {
+        (self.value, self.is_positive)
+    }
+
     pub fn from_uint256(val: Uint256) -> Result<Self, CommonError> {
+        Self::from_uint256_with_scale(val, 0)
+    }
+
+    /// Interprets `val` as a fixed-point amount with `decimal_places`, e.g. a
+    /// token amount of 1_500_000 with 6 decimals becomes 1.5. Digits beyond 18
+    /// decimal places are truncated.
+    pub fn from_uint256_with_scale(val: Uint256, decimal_places: u32) -> CommonResult<Self> {
+        let value = Decimal256::from_atomics(val, decimal_places).map_err(|_| {
+            CommonError::ScaledValueOutOfRange {
+                value: val.to_string(),
+                decimal_places,
+            }
+        })?;
         Ok(Self {
-            value: Decimal256::from_atomics(val, 0u32)
-                .map_err(CommonError::Decimal256RangeExceeded)?,
+            value,
             is_positive: true,
         })
     }
+
+    /// Whole number conversion, which always fits
+    pub fn from_uint128(val: Uint128) -> Self {
+        Self {
+            value: Decimal256::from_atomics(val, 0).unwrap(),
+            is_positive: true,
+        }
+    }
+
+    /// Whole number conversion with the sign of `i`, e.g. `from_int(-3)`,
+    /// which always fits
+    pub fn from_int(i: impl Into<i128>) -> Self {
+        let i = i.into();
+        Self {
+            value: Decimal256::from_atomics(Uint128::new(i.unsigned_abs()), 0).unwrap(),
+            is_positive: i >= 0,
+        }
+    }
+
+    /// Parses an optional field of a message, leaving `None` as `None`
+    pub fn opt_from_str(s: Option<&str>) -> CommonResult<Option<Self>> {
+        Ok(s.map(Self::from_str).transpose()?)
+    }
+
+    /// The value of an optional field, or zero when it was left out
+    pub fn or_zero(value: Option<Self>) -> Self {
+        value.unwrap_or_else(Self::zero)
+    }
+
+    /// Strictly greater than zero, like `Signed::is_positive`
+    pub fn is_gt_zero(&self) -> bool {
+        self.is_positive && !self.value.is_zero()
+    }
+
+    /// Strictly less than zero
+    pub fn is_lt_zero(&self) -> bool {
+        !self.is_positive && !self.value.is_zero()
+    }
+
+    /// Zero or greater
+    pub fn is_gte_zero(&self) -> bool {
+        !self.is_lt_zero()
+    }
+
+    /// Zero or less
+    pub fn is_lte_zero(&self) -> bool {
+        !self.is_gt_zero()
+    }
+
+    /// Whether self lies in `range`, e.g. `x.is_within(&(min..=max))`
+    pub fn is_within<R: RangeBounds<Self>>(&self, range: &R) -> bool {
+        range.contains(self)
+    }
+
+    /// Treats self as a fraction (0.05 is 5%) and returns that share of
+    /// `base`, rounded to a whole number
+    pub fn percent_of(&self, base: Uint256, mode: RoundingMode) -> CommonResult<SignedInt> {
+        let value = mode.div(
+            base.full_mul(self.value.atomics()),
+            Decimal256::one().atomics().into(),
+            self.is_positive,
+        )?;
+        Ok(SignedInt {
+            value,
+            is_positive: self.is_positive || value.is_zero(),
+        })
+    }
+
+    /// Treats self as a fraction and returns that share of `base`, rounded to
+    /// 18 decimal places
+    pub fn of(&self, base: SignedDecimal, mode: RoundingMode) -> CommonResult<Self> {
+        let is_positive = self.is_positive == base.is_positive;
+        let value = Decimal256::new(mode.div(
+            self.value.atomics().full_mul(base.value.atomics()),
+            Decimal256::one().atomics().into(),
+            is_positive,
+        )?);
+        Ok(Self {
+            value,
+            is_positive: is_positive || value.is_zero(),
+        })
+    }
+
+    /// Signed change from `old` to self, e.g. between two snapshots
+    #[cfg(not(feature = "no-panic"))]
+    pub fn delta_since(&self, old: &Self) -> Self {
+        *self - *old
+    }
+
+    /// Compares magnitudes, ignoring signs
+    pub fn cmp_abs(&self, other: &Self) -> Ordering {
+        self.value.cmp(&other.value)
+    }
+
+    /// Whether the magnitudes are equal, ignoring signs
+    pub fn eq_abs(&self, other: &Self) -> bool {
+        self.value == other.value
+    }
+
+    /// Whether self equals the unsigned `other`, treating zero of either sign
+    /// as equal to `Decimal256::zero()`
+    pub fn eq_decimal(&self, other: &Decimal256) -> bool {
+        (self.is_positive || self.value.is_zero()) && self.value == *other
+    }
+
+    /// Decimal counterpart of [`SignedInt::to_i64_saturating`], so `exp = 6`
+    /// reports millionths
+    pub fn to_i64_saturating(&self, exp: i32) -> i64 {
+        SignedInt {
+            value: self.value.atomics(),
+            is_positive: self.is_positive,
+        }
+        .to_i64_saturating(exp.saturating_sub(Self::DECIMAL_PLACES as i32))
+    }
+
+    /// Nearest f64, which is lossy, e.g. for charts and off-chain analytics
+    pub fn to_f64(&self) -> f64 {
+        // Parsing the decimal string rounds correctly
+        f64::from_str(&self.to_string()).unwrap()
+    }
+
+    /// The shortest decimal that reads back as `v`, so 0.1 becomes 0.1
+    /// rather than its binary expansion, rounded to 18 decimal places. Errors
+    /// for NaN, infinities and magnitudes beyond `MAX`.
+    pub fn try_from_f64(v: f64) -> CommonResult<Self> {
+        let overflow = || ConversionOverflowError::new("f64", "SignedDecimal", v.to_string());
+        if !v.is_finite() {
+            return Err(overflow().into());
+        }
+        let text = match v.to_string().split_once('.') {
+            Some((_, fraction)) if fraction.len() > Self::DECIMAL_PLACES as usize => {
+                format!("{:.*}", Self::DECIMAL_PLACES as usize, v)
+            }
+            _ => v.to_string(),
+        };
+        Self::from_str(&text).map_err(|_| overflow().into())
+    }
+
+    /// Largest integer not above self, e.g. -2 for -1.5
+    pub fn to_signed_int_floor(&self) -> SignedInt {
+        self.to_int_with(RoundingMode::Floor)
+    }
+
+    /// Smallest integer not below self, e.g. -1 for -1.5
+    pub fn to_signed_int_ceil(&self) -> SignedInt {
+        self.to_int_with(RoundingMode::Ceil)
+    }
+
+    /// Integer part, truncated toward zero, e.g. -1 for -1.5
+    pub fn to_signed_int_trunc(&self) -> SignedInt {
+        self.to_int_with(RoundingMode::TowardZero)
+    }
+
+    /// Drops the fraction whatever the sign, e.g. an order size that must
+    /// never exceed what was asked for in either direction. Same as
+    /// [`SignedDecimal::to_signed_int_trunc`].
+    pub fn to_int_toward_zero(&self) -> SignedInt {
+        self.to_int_with(RoundingMode::TowardZero)
+    }
+
+    /// Rounds any fraction up in magnitude, e.g. -2 for -1.2, for amounts
+    /// that must at least cover self
+    pub fn to_int_away_from_zero(&self) -> SignedInt {
+        self.to_int_with(RoundingMode::AwayFromZero)
+    }
+
+    /// Rounds to an integer with `mode`, e.g. a signed price delta to a token
+    /// amount. Floor takes -1.5 to -2 and HalfUp takes it to -2 as well.
+    pub fn to_int_with(&self, mode: RoundingMode) -> SignedInt {
+        // The quotient is below 2^256 / 10^18, so rounding away from zero
+        // can't overflow
+        let value = mode
+            .div(
+                self.value.atomics().into(),
+                Decimal256::one().atomics().into(),
+                self.is_positive,
+            )
+            .unwrap();
+        SignedInt {
+            value,
+            is_positive: self.is_positive || value.is_zero(),
+        }
+    }
+
+    /// Parses the Cosmos SDK `LegacyDec` wire form, an integer string of the
+    /// value times 10^18, so "-1500000000000000000" is -1.5
+    pub fn from_sdk_dec_str(s: &str) -> CommonResult<Self> {
+        Ok(Self::from_atomics_int(SignedInt::from_str(s)?))
+    }
+
+    /// The Cosmos SDK `LegacyDec` wire form, see
+    /// [`SignedDecimal::from_sdk_dec_str`]
+    pub fn to_sdk_dec_str(&self) -> String {
+        self.atomics_int().to_string()
+    }
+
+    /// The atomics (value times 10^18) as an i128, or a conversion overflow
+    /// when they don't fit
+    pub fn atomics_i128(&self) -> CommonResult<i128> {
+        i128::try_from(self.atomics_int())
+    }
+
+    /// The atomics (value times 10^18) as an i64, or a conversion overflow
+    /// when they don't fit, which is any magnitude from about 9.22
+    pub fn atomics_i64(&self) -> CommonResult<i64> {
+        i64::try_from(self.atomics_int())
+    }
+
+    /// Rounds onto the grid `offset + k * increment` for integer `k`.
+    ///
+    /// `TowardZero`, `AwayFromZero` and `HalfUp` ties pick the grid point in
+    /// the corresponding direction from self, which may cross zero when the
+    /// offset isn't a multiple of the increment.
+    pub fn quantize(
+        &self,
+        increment: Decimal256,
+        offset: Decimal256,
+        mode: RoundingMode,
+    ) -> CommonResult<Self> {
+        let diff = self.checked_sub(Self::from(offset))?;
+        let inc = increment.atomics();
+        let rem = diff
+            .value
+            .atomics()
+            .checked_rem(inc)
+            .map_err(|_| DivideByZeroError::new(*self))?;
+        if rem.is_zero() {
+            return Ok(*self);
+        }
+        // Distance from self down to the closest grid point below it
+        let below = if diff.is_positive { rem } else { inc - rem };
+        let round_up = match mode {
+            RoundingMode::Floor => false,
+            RoundingMode::Ceil => true,
+            RoundingMode::TowardZero => !self.is_positive,
+            RoundingMode::AwayFromZero => self.is_positive,
+            RoundingMode::HalfUp => match below.cmp(&(inc - below)) {
+                Ordering::Less => false,
+                Ordering::Greater => true,
+                Ordering::Equal => self.is_positive,
+            },
+        };
+        let lower = self.checked_sub(Self::from(Decimal256::new(below)))?;
+        if round_up {
+            lower.checked_add(Self::from(increment))
+        } else {
+            Ok(lower)
+        }
+    }
+
+    /// Index of the bucket of width `bucket_size` holding self, so bucket `k`
+    /// covers `[k * bucket_size, (k + 1) * bucket_size)`. Negative values
+    /// round toward negative infinity, so -0.5 with a size of 1 is in -1.
+    pub fn bucket(&self, bucket_size: Decimal256) -> CommonResult<SignedInt> {
+        let value = RoundingMode::Floor.div(
+            self.value.atomics().into(),
+            bucket_size.atomics().into(),
+            self.is_positive,
+        )?;
+        Ok(SignedInt {
+            value,
+            is_positive: self.is_positive || value.is_zero(),
+        })
+    }
+
+    /// Inclusive lower and exclusive upper bound of bucket `index`, the
+    /// inverse of [`SignedDecimal::bucket`]
+    pub fn bucket_bounds(index: SignedInt, bucket_size: Decimal256) -> CommonResult<(Self, Self)> {
+        let edge = |k: SignedInt| -> CommonResult<Self> {
+            let value = Decimal256::new(k.value.checked_mul(bucket_size.atomics())?);
+            Ok(Self {
+                value,
+                is_positive: k.is_positive || value.is_zero(),
+            })
+        };
+        Ok((
+            edge(index)?,
+            edge(index.checked_add(SignedInt::from_u128(1))?)?,
+        ))
+    }
+
+    pub fn checked_add(self, other: Self) -> CommonResult<Self> {
+        self.signed_add(other)
+            .ok_or_else(|| OverflowError::new(OverflowOperation::Add, self, other).into())
+    }
+
+    pub fn checked_sub(self, other: Self) -> CommonResult<Self> {
+        self.signed_add(-other)
+            .ok_or_else(|| OverflowError::new(OverflowOperation::Sub, self, other).into())
+    }
+
+    /// Sign-magnitude sum, or `None` on overflow. Building the error is left
+    /// to the callers so a failed op reports exactly once.
+    fn signed_add(self, other: Self) -> Option<Self> {
+        if self.is_positive != other.is_positive {
+            // Magnitudes with opposite signs shrink, so this can't overflow
+            return Some(match self.value.cmp(&other.value) {
+                Ordering::Greater => Self {
+                    value: self.value - other.value,
+                    is_positive: self.is_positive,
+                },
+                Ordering::Less => Self {
+                    value: other.value - self.value,
+                    is_positive: other.is_positive,
+                },
+                Ordering::Equal => Self::zero(),
+            });
+        }
+        // Decimal256::checked_add is missing from cosmwasm 1.0, so add the atomics
+        let atomics = self
+            .value
+            .atomics()
+            .checked_add(other.value.atomics())
+            .ok()?;
+        Some(Self {
+            value: Decimal256::new(atomics),
+            is_positive: self.is_positive,
+        })
+    }
+
+    /// Product truncated toward zero like `Mul`, but erroring instead of
+    /// panicking when it exceeds the Decimal256 range
+    pub fn checked_mul(self, other: Self) -> CommonResult<Self> {
+        let atomics = self.value.atomics().full_mul(other.value.atomics())
+            / Uint512::from(10u128.pow(Self::DECIMAL_PLACES));
+        let atomics = Uint256::try_from(atomics)
+            .map_err(|_| OverflowError::new(OverflowOperation::Mul, self, other))?;
+        Ok(Self::from_atomics_int(SignedInt {
+            value: atomics,
+            is_positive: self.is_positive == other.is_positive,
+        }))
+    }
+
+    /// Quotient truncated toward zero like `Div`, but erroring on a zero
+    /// divisor rather than returning zero
+    pub fn checked_div(self, other: Self) -> CommonResult<Self> {
+        if other.is_zero() {
+            return Err(DivideByZeroError::new(self).into());
+        }
+        let is_positive = self.is_positive == other.is_positive;
+        let atomics = RoundingMode::TowardZero
+            .div(
+                self.value
+                    .atomics()
+                    .full_mul(10u128.pow(Self::DECIMAL_PLACES)),
+                other.value.atomics().into(),
+                is_positive,
+            )
+            .map_err(|_| OverflowError::new(OverflowOperation::Mul, self, other))?;
+        Ok(Self::from_atomics_int(SignedInt {
+            value: atomics,
+            is_positive,
+        }))
+    }
+
+    /// Quotient that errors instead of rounding, when it has digits beyond 18
+    /// decimal places, e.g. for share and redeem amounts that must be exact
+    pub fn div_exact(self, other: Self) -> CommonResult<Self> {
+        if other.is_zero() {
+            return Err(DivideByZeroError::new(self).into());
+        }
+        let remainder = self
+            .value
+            .atomics()
+            .full_mul(10u128.pow(Self::DECIMAL_PLACES))
+            .checked_rem(other.value.atomics().into())?;
+        if !remainder.is_zero() {
+            return Err(CommonError::Generic(format!(
+                "{} / {} is not exact at {} decimal places",
+                self.to_string(),
+                other.to_string(),
+                Self::DECIMAL_PLACES
+            )));
+        }
+        self.checked_div(other)
+    }
+
+    /// Multiplies by `10^exp`, or divides by `10^-exp` truncating toward zero
+    /// when `exp` is negative, e.g. to rescale token amounts
+    pub fn mul_pow10(self, exp: i32) -> CommonResult<Self> {
+        let atomics = shift_pow10(self.value.atomics(), exp)
+            .ok_or_else(|| OverflowError::new(OverflowOperation::Mul, self, format!("1e{exp}")))?;
+        Ok(Self::from_atomics_int(SignedInt {
+            value: atomics,
+            is_positive: self.is_positive,
+        }))
+    }
+
+    /// Divides by `10^exp` truncating toward zero, the inverse of
+    /// [`SignedDecimal::mul_pow10`]
+    pub fn div_pow10(self, exp: i32) -> CommonResult<Self> {
+        self.mul_pow10(exp.checked_neg().unwrap_or(i32::MAX))
+    }
+
+    /// Negation keeping zero positive. Never fails, the result type matches
+    /// [`SignedInt::checked_neg`].
+    pub fn checked_neg(self) -> CommonResult<Self> {
+        Ok(Self {
+            value: self.value,
+            is_positive: !self.is_positive || self.value.is_zero(),
+        })
+    }
+
+    /// Magnitude as a non-negative value. Never fails.
+    pub fn checked_abs(self) -> CommonResult<Self> {
+        Ok(Self::from(self.value))
+    }
+
+    /// Sum that panics on overflow, which the `+` operator forwards to
+    #[cfg(not(feature = "no-panic"))]
+    pub fn strict_add(self, other: Self) -> Self {
+        self.checked_add(other)
+            .expect("attempt to add with overflow")
+    }
+
+    /// Difference that panics on overflow, which the `-` operator forwards to
+    #[cfg(not(feature = "no-panic"))]
+    pub fn strict_sub(self, other: Self) -> Self {
+        self.checked_sub(other)
+            .expect("attempt to subtract with overflow")
+    }
+
+    /// Product that panics on overflow, which the `*` operator forwards to
+    #[cfg(not(feature = "no-panic"))]
+    pub fn strict_mul(self, other: Self) -> Self {
+        self.checked_mul(other)
+            .expect("attempt to multiply with overflow")
+    }
+
+    /// Quotient that panics on a zero divisor or overflow
+    #[cfg(not(feature = "no-panic"))]
+    pub fn strict_div(self, other: Self) -> Self {
+        self.checked_div(other)
+            .unwrap_or_else(|err| panic!("{err}"))
+    }
+
+    pub fn saturating_add(self, other: Self) -> Self {
+        self.checked_add(other).unwrap_or(if self.is_positive {
+            Self::MAX
+        } else {
+            Self::MIN
+        })
+    }
+
+    pub fn saturating_sub(self, other: Self) -> Self {
+        self.checked_sub(other).unwrap_or(if self.is_positive {
+            Self::MAX
+        } else {
+            Self::MIN
+        })
+    }
+
+    pub fn saturating_mul(self, other: Self) -> Self {
+        self.checked_mul(other)
+            .unwrap_or(if self.is_positive == other.is_positive {
+                Self::MAX
+            } else {
+                Self::MIN
+            })
+    }
+
+    /// Quotient truncated toward zero, clamping on overflow. A zero divisor
+    /// gives MAX or MIN by the sign of self, or zero for zero.
+    pub fn saturating_div(self, other: Self) -> Self {
+        if self.is_zero() {
+            return Self::zero();
+        }
+        self.checked_div(other)
+            .unwrap_or(if self.is_positive == other.is_positive {
+                Self::MAX
+            } else {
+                Self::MIN
+            })
+    }
+
+    /// Truncated remainder, the result takes the sign of self
+    pub fn checked_rem(self, other: Self) -> CommonResult<Self> {
+        let atomics = self
+            .value
+            .atomics()
+            .checked_rem(other.value.atomics())
+            .map_err(|_| DivideByZeroError::new(self))?;
+        Ok(Self::from_atomics_int(SignedInt {
+            value: atomics,
+            is_positive: self.is_positive,
+        }))
+    }
+
+    /// Whole number quotient rounded so that
+    /// [`SignedDecimal::checked_rem_euclid`] is never negative, e.g. the index
+    /// of the funding period holding a timestamp offset
+    pub fn checked_div_euclid(self, other: Self) -> CommonResult<Self> {
+        let whole = self
+            .value
+            .atomics()
+            .checked_div(other.value.atomics())
+            .map_err(|_| DivideByZeroError::new(self))?;
+        let truncated = SignedInt {
+            value: whole,
+            is_positive: self.is_positive == other.is_positive || whole.is_zero(),
+        };
+        let quotient = if self.checked_rem(other)?.is_gte_zero() {
+            truncated
+        } else if other.is_positive {
+            truncated.checked_sub(SignedInt::one())?
+        } else {
+            truncated.checked_add(SignedInt::one())?
+        };
+        Self::zero().checked_add_int(quotient, IntScale::Whole)
+    }
+
+    /// Remainder in `[0, |other|)`, erroring on a zero divisor
+    pub fn checked_rem_euclid(self, other: Self) -> CommonResult<Self> {
+        let rem = self.checked_rem(other)?;
+        if rem.is_lt_zero() {
+            rem.checked_add(other.checked_abs()?)
+        } else {
+            Ok(rem)
+        }
+    }
+
+    /// Saturated sum and whether it overflowed, like
+    /// [`SignedInt::overflowing_add`]
+    pub fn overflowing_add(self, other: Self) -> (Self, bool) {
+        // One checked op, so a failure is traced once
+        match self.checked_add(other) {
+            Ok(value) => (value, false),
+            Err(_) if self.is_positive => (Self::MAX, true),
+            Err(_) => (Self::MIN, true),
+        }
+    }
+
+    /// Saturated difference and whether it overflowed
+    pub fn overflowing_sub(self, other: Self) -> (Self, bool) {
+        match self.checked_sub(other) {
+            Ok(value) => (value, false),
+            Err(_) if self.is_positive => (Self::MAX, true),
+            Err(_) => (Self::MIN, true),
+        }
+    }
+
+    /// Saturated product and whether it overflowed
+    pub fn overflowing_mul(self, other: Self) -> (Self, bool) {
+        match self.checked_mul(other) {
+            Ok(value) => (value, false),
+            Err(_) if self.is_positive == other.is_positive => (Self::MAX, true),
+            Err(_) => (Self::MIN, true),
+        }
+    }
+
+    /// Adds `rhs` read with `scale`, erroring instead of mixing units
+    /// silently. NaN is rejected.
+    pub fn checked_add_int(self, rhs: SignedInt, scale: IntScale) -> CommonResult<Self> {
+        let sum = self
+            .atomics_int()
+            .checked_add(Self::int_atomics(rhs, scale)?)?;
+        Ok(Self::from_atomics_int(sum))
+    }
+
+    /// Subtracts `rhs` read with `scale`, see [`SignedDecimal::checked_add_int`]
+    pub fn checked_sub_int(self, rhs: SignedInt, scale: IntScale) -> CommonResult<Self> {
+        let diff = self
+            .atomics_int()
+            .checked_sub(Self::int_atomics(rhs, scale)?)?;
+        Ok(Self::from_atomics_int(diff))
+    }
+
+    fn atomics_int(self) -> SignedInt {
+        SignedInt {
+            value: self.value.atomics(),
+            is_positive: self.is_positive,
+        }
+    }
+
+    fn from_atomics_int(atomics: SignedInt) -> Self {
+        Self {
+            value: Decimal256::new(atomics.value),
+            is_positive: atomics.is_positive || atomics.value.is_zero(),
+        }
+    }
+
+    fn int_atomics(int: SignedInt, scale: IntScale) -> CommonResult<SignedInt> {
+        if int.is_nan() {
+            return Err(CommonError::Generic(
+                "NaN can't be combined with a SignedDecimal".to_string(),
+            ));
+        }
+        match scale {
+            IntScale::Whole => {
+                int.checked_mul(SignedInt::from_u128(10u128.pow(Self::DECIMAL_PLACES)))
+            }
+            IntScale::Atomics => Ok(int),
+        }
+    }
+
+    /// Floor of log10 of the magnitude, e.g. 2 for -150 and -3 for 0.0012, or
+    /// `None` for zero
+    pub fn order_of_magnitude(&self) -> Option<i32> {
+        let atomics = self.value.atomics();
+        if atomics.is_zero() {
+            return None;
+        }
+        let digits = atomics.to_string().len() as i32;
+        Some(digits - 1 - Self::DECIMAL_PLACES as i32)
+    }
 }
 
-impl Mul<SignedDecimal> for Uint256 {
+#[cfg(not(feature = "no-panic"))]
+impl std::ops::Mul<SignedDecimal> for Uint256 {
     type Output = SignedInt;
 
     fn mul(self, rhs: SignedDecimal) -> Self::Output {
+        let value = rhs.value * self;
         SignedInt {
-            value: rhs.value * self,
-            is_positive: rhs.is_positive,
+            value,
+            is_positive: rhs.is_positive || value.is_zero(),
         }
     }
 }
 
-impl Mul<Decimal256> for SignedDecimal {
+#[cfg(not(feature = "no-panic"))]
+impl std::ops::Mul<Decimal256> for SignedDecimal {
     type Output = SignedDecimal;
 
     fn mul(mut self, rhs: Decimal256) -> Self::Output {
         self.value *= rhs;
+        self.is_positive |= self.value.is_zero();
         self
     }
 }
@@ -59,24 +790,37 @@ impl Neg for SignedDecimal {
     type Output = Self;
 
     fn neg(self) -> Self::Output {
-        if self.is_zero() {
-            return self;
-        }
-        Self {
-            value: self.value,
-            is_positive: !self.is_positive,
-        }
+        // checked_neg can't fail for decimals
+        self.checked_neg().unwrap_or(self)
     }
 }
 
-impl Rem for SignedDecimal {
+#[cfg(not(feature = "no-panic"))]
+impl std::ops::Rem for SignedDecimal {
     type Output = Self;
 
+    /// Truncated remainder taking the sign of self, panicking on a zero
+    /// divisor
     fn rem(self, rhs: Self) -> Self::Output {
-        Decimal256::new(self.value.atomics().rem(rhs.value.atomics())).into()
+        self.checked_rem(rhs)
+            .expect("attempt to calculate the remainder with a divisor of zero")
+    }
+}
+
+#[cfg(not(feature = "no-panic"))]
+impl num_traits::Euclid for SignedDecimal {
+    fn div_euclid(&self, v: &Self) -> Self {
+        self.checked_div_euclid(*v)
+            .unwrap_or_else(|err| panic!("{err}"))
+    }
+
+    fn rem_euclid(&self, v: &Self) -> Self {
+        self.checked_rem_euclid(*v)
+            .unwrap_or_else(|err| panic!("{err}"))
     }
 }
 
+#[cfg(not(feature = "no-panic"))]
 impl One for SignedDecimal {
     fn one() -> Self {
         Self {
@@ -86,6 +830,7 @@ impl One for SignedDecimal {
     }
 }
 
+#[cfg(not(feature = "no-panic"))]
 impl Zero for SignedDecimal {
     fn zero() -> Self {
         Self {
@@ -99,43 +844,104 @@ impl Zero for SignedDecimal {
     }
 }
 
+/// Only radix 10 is supported, parsed like `FromStr` with up to 18
+/// fractional digits
+#[cfg(not(feature = "no-panic"))]
 impl Num for SignedDecimal {
-    type FromStrRadixErr = StdError;
+    type FromStrRadixErr = ParseSignedError;
 
-    fn from_str_radix(_str: &str, _radix: u32) -> Result<Self, Self::FromStrRadixErr> {
-        panic!("unimplemented")
+    fn from_str_radix(s: &str, radix: u32) -> Result<Self, Self::FromStrRadixErr> {
+        if radix != 10 {
+            return Err(ParseSignedError::InvalidRadix { radix });
+        }
+        Self::from_str(s)
     }
 }
 
+#[cfg(not(feature = "no-panic"))]
 impl num_traits::sign::Signed for SignedDecimal {
     fn abs(&self) -> Self {
-        Self {
-            value: self.value,
-            is_positive: true,
-        }
+        self.checked_abs().unwrap_or(*self)
     }
 
+    /// `self - other` when self is greater, otherwise zero. Saturates rather
+    /// than overflowing.
     fn abs_sub(&self, other: &Self) -> Self {
-        let new = *self - *other;
-        new.abs()
+        if self <= other {
+            Self::zero()
+        } else {
+            self.saturating_sub(*other)
+        }
     }
 
+    /// Zero for zero, otherwise one with the sign of the value
     fn signum(&self) -> Self {
-        match self.is_positive {
-            true => Self::one(),
-            false => Self {
-                value: Decimal256::one(),
-                is_positive: false,
-            },
+        if self.value.is_zero() {
+            Self::zero()
+        } else if self.is_positive {
+            Self::one()
+        } else {
+            -Self::one()
         }
     }
 
     fn is_positive(&self) -> bool {
-        self.is_positive
+        self.is_gt_zero()
     }
 
     fn is_negative(&self) -> bool {
-        !self.is_positive
+        self.is_lt_zero()
+    }
+}
+
+/// Integer conversions truncate toward zero, like those of f64
+impl num_traits::ToPrimitive for SignedDecimal {
+    fn to_i64(&self) -> Option<i64> {
+        self.to_signed_int_trunc().to_i64()
+    }
+
+    fn to_u64(&self) -> Option<u64> {
+        self.to_signed_int_trunc().to_u64()
+    }
+
+    fn to_i128(&self) -> Option<i128> {
+        self.to_signed_int_trunc().to_i128()
+    }
+
+    fn to_u128(&self) -> Option<u128> {
+        self.to_signed_int_trunc().to_u128()
+    }
+
+    fn to_f64(&self) -> Option<f64> {
+        Some(SignedDecimal::to_f64(self))
+    }
+}
+
+/// Floats go through [`SignedDecimal::try_from_f64`]
+impl num_traits::FromPrimitive for SignedDecimal {
+    fn from_i64(n: i64) -> Option<Self> {
+        Some(Self::from_int(n))
+    }
+
+    fn from_u64(n: u64) -> Option<Self> {
+        Some(Self::from(n))
+    }
+
+    fn from_i128(n: i128) -> Option<Self> {
+        Some(Self::from_int(n))
+    }
+
+    fn from_u128(n: u128) -> Option<Self> {
+        Some(Self::from_uint128(Uint128::new(n)))
+    }
+
+    /// Uses the shortest decimal for the f32 itself, so 0.1f32 becomes 0.1
+    fn from_f32(n: f32) -> Option<Self> {
+        Self::from_f64(f64::from_str(&n.to_string()).ok()?)
+    }
+
+    fn from_f64(n: f64) -> Option<Self> {
+        Self::try_from_f64(n).ok()
     }
 }
 
@@ -150,58 +956,65 @@ impl ToString for SignedDecimal {
     }
 }
 
+#[cfg(not(feature = "no-panic"))]
 impl std::ops::Add<Self> for SignedDecimal {
     type Output = Self;
 
     fn add(self, rhs: Self) -> Self {
-        let value;
-        let is_positive;
-        if self.is_positive == rhs.is_positive {
-            value = self.value + rhs.value;
-            is_positive = self.is_positive;
-        } else if self.value > rhs.value {
-            value = self.value - rhs.value;
-            is_positive = self.is_positive;
-        } else if self.value < rhs.value {
-            value = rhs.value - self.value;
-            is_positive = rhs.is_positive
-        } else {
-            value = Decimal256::zero();
-            is_positive = true;
-        }
-        Self { is_positive, value }
+        self.strict_add(rhs)
     }
 }
 
+#[cfg(not(feature = "no-panic"))]
 impl std::ops::AddAssign<Self> for SignedDecimal {
     fn add_assign(&mut self, rhs: Self) {
         *self = *self + rhs;
     }
 }
 
+/// Treats the SignedInt as a whole number, use
+/// [`SignedDecimal::checked_add_int`] for atomics
+#[cfg(not(feature = "no-panic"))]
+impl std::ops::Add<SignedInt> for SignedDecimal {
+    type Output = Self;
+
+    fn add(self, rhs: SignedInt) -> Self {
+        self.checked_add_int(rhs, IntScale::Whole).unwrap()
+    }
+}
+
+#[cfg(not(feature = "no-panic"))]
 impl std::ops::Sub<Self> for SignedDecimal {
     type Output = Self;
 
     fn sub(self, rhs: Self) -> Self {
-        self + Self {
-            value: rhs.value,
-            is_positive: !rhs.is_positive,
-        }
+        self.strict_sub(rhs)
     }
 }
 
+/// Treats the SignedInt as a whole number, use
+/// [`SignedDecimal::checked_sub_int`] for atomics
+#[cfg(not(feature = "no-panic"))]
+impl std::ops::Sub<SignedInt> for SignedDecimal {
+    type Output = Self;
+
+    fn sub(self, rhs: SignedInt) -> Self {
+        self.checked_sub_int(rhs, IntScale::Whole).unwrap()
+    }
+}
+
+#[cfg(not(feature = "no-panic"))]
 impl std::ops::Mul<Self> for SignedDecimal {
     type Output = Self;
 
     fn mul(self, rhs: Self) -> Self {
-        let value = self.value * rhs.value;
-        Self {
-            value,
-            is_positive: self.is_positive == rhs.is_positive || value.is_zero(),
-        }
+        self.strict_mul(rhs)
     }
 }
 
+/// Unlike [`SignedDecimal::strict_div`], returns zero for a zero divisor, which
+/// existing callers rely on
+#[cfg(not(feature = "no-panic"))]
 impl std::ops::Div<Self> for SignedDecimal {
     type Output = Self;
 
@@ -220,32 +1033,29 @@ impl std::ops::Div<Self> for SignedDecimal {
 
 impl std::cmp::PartialEq for SignedDecimal {
     fn eq(&self, other: &Self) -> bool {
-        if self.is_zero() {
-            return other.is_zero();
-        }
-        self.value == other.value && self.is_positive == other.is_positive
+        self.cmp(other) == Ordering::Equal
     }
 }
 
 impl std::cmp::PartialOrd for SignedDecimal {
-    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
-        if self.is_positive == other.is_positive {
-            if self.is_positive {
-                self.value.partial_cmp(&other.value)
-            } else {
-                other.value.partial_cmp(&self.value)
-            }
-        } else if self.is_positive {
-            Some(std::cmp::Ordering::Greater)
-        } else {
-            Some(std::cmp::Ordering::Less)
-        }
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
     }
 }
 
+/// The usual order of the numbers they represent, which is total. A zero
+/// magnitude compares equal to zero whatever its sign flag, so a stray
+/// negative zero still agrees with `Eq` when used as a map key.
 impl std::cmp::Ord for SignedDecimal {
-    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
-        self.partial_cmp(other).unwrap()
+    fn cmp(&self, other: &Self) -> Ordering {
+        // Signs decide most comparisons without touching the magnitudes
+        match (self.is_positive, other.is_positive) {
+            (true, true) => self.value.cmp(&other.value),
+            (false, false) => other.value.cmp(&self.value),
+            _ if self.value.is_zero() && other.value.is_zero() => Ordering::Equal,
+            (true, false) => Ordering::Greater,
+            (false, true) => Ordering::Less,
+        }
     }
 }
 
@@ -258,23 +1068,75 @@ impl From<Decimal256> for SignedDecimal {
     }
 }
 
+/// Builds from a magnitude and `is_positive` flag. A zero magnitude is
+/// always non-negative, whatever the flag.
+impl From<(Decimal256, bool)> for SignedDecimal {
+    fn from((value, is_positive): (Decimal256, bool)) -> Self {
+        Self {
+            value,
+            is_positive: is_positive || value.is_zero(),
+        }
+    }
+}
+
+/// Fails with a conversion overflow for NaN or a magnitude beyond
+/// `SignedDecimal::MAX`
+impl TryFrom<SignedInt> for SignedDecimal {
+    type Error = CommonError;
+
+    fn try_from(int: SignedInt) -> CommonResult<Self> {
+        Self::int_atomics(int, IntScale::Whole)
+            .map(Self::from_atomics_int)
+            .map_err(|_| {
+                ConversionOverflowError::new("SignedInt", "SignedDecimal", int.to_string()).into()
+            })
+    }
+}
+
+/// Only whole numbers convert, see [`SignedDecimal::to_int_with`] to round
+impl TryFrom<SignedDecimal> for SignedInt {
+    type Error = CommonError;
+
+    fn try_from(value: SignedDecimal) -> CommonResult<Self> {
+        let fraction = value.value.atomics() % Decimal256::one().atomics();
+        if !fraction.is_zero() {
+            return Err(CommonError::Generic(format!(
+                "{} is not a whole number",
+                value.to_string()
+            )));
+        }
+        Ok(value.to_signed_int_trunc())
+    }
+}
+
+impl From<i64> for SignedDecimal {
+    fn from(value: i64) -> Self {
+        Self::from_int(value)
+    }
+}
+
+impl From<i128> for SignedDecimal {
+    fn from(value: i128) -> Self {
+        Self::from_int(value)
+    }
+}
+
+impl From<u64> for SignedDecimal {
+    fn from(value: u64) -> Self {
+        Self::from_uint128(Uint128::from(value))
+    }
+}
+
 impl FromStr for SignedDecimal {
-    type Err = CommonError;
+    type Err = ParseSignedError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let sign;
-        let val_str;
-        let mut chars = s.chars();
-        if chars.next().unwrap() == '-' {
-            sign = false;
-            val_str = chars.as_str();
-        } else {
-            sign = true;
-            val_str = s;
-        }
+        let (sign, val_str) = ParseSignedError::split_sign(s, Self::DECIMAL_PLACES)?;
+        let value =
+            Decimal256::from_str(val_str).map_err(|_| ParseSignedError::MagnitudeTooLarge)?;
         Ok(Self {
-            value: Decimal256::from_str(val_str)?,
-            is_positive: sign,
+            value,
+            is_positive: sign || value.is_zero(),
         })
     }
 }
@@ -289,7 +1151,8 @@ impl Serialize for SignedDecimal {
     }
 }
 
-/// Deserializes as a base64 string
+/// Deserializes from a decimal string. For JSON payloads that send numbers,
+/// see `serde_helpers::deserialize_json_number`.
 impl<'de> Deserialize<'de> for SignedDecimal {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
     where
@@ -339,7 +1202,7 @@ impl TryFrom<&str> for SignedDecimal {
     type Error = CommonError;
 
     fn try_from(value: &str) -> Result<Self, Self::Error> {
-        Self::from_str(value)
+        Ok(Self::from_str(value)?)
     }
 }
 
@@ -366,6 +1229,7 @@ impl Default for SignedDecimal {
 }
 
 #[test]
+#[cfg(not(feature = "no-panic"))]
 fn signed_decimal_test() {
     let big_pos = SignedDecimal::from_str("100").unwrap();
     let big_neg = SignedDecimal::from_str("-100").unwrap();
@@ -434,6 +1298,7 @@ fn signed_decimal_test() {
 }
 
 #[test]
+#[cfg(not(feature = "no-panic"))]
 fn test_zero_is_positive() {
     {
         let mut x = SignedDecimal::zero();
@@ -485,3 +1350,911 @@ fn test_zero_is_positive() {
         assert!(z.is_positive);
     }
 }
+
+#[test]
+fn test_quantize() {
+    let dec = |s: &str| SignedDecimal::from_str(s).unwrap();
+    let increment = Decimal256::from_str("0.25").unwrap();
+    let offset = Decimal256::from_str("0.1").unwrap();
+    let quantize = |s: &str, mode| dec(s).quantize(increment, offset, mode).unwrap();
+
+    assert_eq!(quantize("0.35", RoundingMode::Floor), dec("0.35"));
+    assert_eq!(quantize("-0.65", RoundingMode::Ceil), dec("-0.65"));
+
+    assert_eq!(quantize("0.5", RoundingMode::Floor), dec("0.35"));
+    assert_eq!(quantize("0.5", RoundingMode::Ceil), dec("0.6"));
+    assert_eq!(quantize("0.5", RoundingMode::TowardZero), dec("0.35"));
+    assert_eq!(quantize("0.5", RoundingMode::AwayFromZero), dec("0.6"));
+    assert_eq!(quantize("0.5", RoundingMode::HalfUp), dec("0.6"));
+    assert_eq!(quantize("0.45", RoundingMode::HalfUp), dec("0.35"));
+    assert_eq!(quantize("0.475", RoundingMode::HalfUp), dec("0.6"));
+
+    assert_eq!(quantize("-0.2", RoundingMode::Floor), dec("-0.4"));
+    assert_eq!(quantize("-0.2", RoundingMode::Ceil), dec("-0.15"));
+    assert_eq!(quantize("-0.2", RoundingMode::TowardZero), dec("-0.15"));
+    assert_eq!(quantize("-0.2", RoundingMode::AwayFromZero), dec("-0.4"));
+    assert_eq!(quantize("-0.275", RoundingMode::HalfUp), dec("-0.4"));
+    assert_eq!(quantize("-0.25", RoundingMode::HalfUp), dec("-0.15"));
+
+    assert_eq!(quantize("0.05", RoundingMode::Floor), dec("-0.15"));
+    assert_eq!(
+        dec("1").quantize(Decimal256::zero(), offset, RoundingMode::Floor),
+        Err(CommonError::DivideByZero(DivideByZeroError::new("1")))
+    );
+    assert!(matches!(
+        SignedDecimal::MIN.quantize(Decimal256::one(), offset, RoundingMode::Floor),
+        Err(CommonError::Overflow(_))
+    ));
+    assert!(matches!(
+        SignedDecimal::MAX.quantize(Decimal256::one(), offset, RoundingMode::Ceil),
+        Err(CommonError::Overflow(_))
+    ));
+}
+
+#[test]
+fn test_bucket() {
+    let dec = |s: &str| SignedDecimal::from_str(s).unwrap();
+    let int = |s: &str| SignedInt::from_str(s).unwrap();
+    let size = Decimal256::from_str("0.5").unwrap();
+
+    assert_eq!(dec("0").bucket(size).unwrap(), int("0"));
+    assert_eq!(dec("0.49").bucket(size).unwrap(), int("0"));
+    assert_eq!(dec("0.5").bucket(size).unwrap(), int("1"));
+    assert_eq!(dec("-0.1").bucket(size).unwrap(), int("-1"));
+    assert_eq!(dec("-0.5").bucket(size).unwrap(), int("-1"));
+    assert_eq!(dec("-0.51").bucket(size).unwrap(), int("-2"));
+    assert!(dec("1").bucket(Decimal256::zero()).is_err());
+
+    assert_eq!(
+        SignedDecimal::bucket_bounds(int("-1"), size).unwrap(),
+        (dec("-0.5"), dec("0"))
+    );
+    assert_eq!(
+        SignedDecimal::bucket_bounds(int("3"), size).unwrap(),
+        (dec("1.5"), dec("2"))
+    );
+    for s in ["-7.3", "-0.000000000000000001", "0", "2.25"] {
+        let (lower, upper) =
+            SignedDecimal::bucket_bounds(dec(s).bucket(size).unwrap(), size).unwrap();
+        assert!(lower <= dec(s) && dec(s) < upper);
+    }
+}
+
+#[test]
+fn test_checked_add_sub() {
+    let dec = |s: &str| SignedDecimal::from_str(s).unwrap();
+    let max = SignedDecimal::from(Decimal256::MAX);
+
+    assert_eq!(dec("1.5").checked_add(dec("-2")).unwrap(), dec("-0.5"));
+    assert_eq!(dec("-1.5").checked_sub(dec("-1.5")).unwrap(), dec("0"));
+    assert!(dec("-1.5").checked_add(dec("1.5")).unwrap().is_positive);
+    assert_eq!(max.checked_sub(max).unwrap(), dec("0"));
+    assert_eq!(max.checked_add(-max).unwrap(), dec("0"));
+    assert!(matches!(
+        max.checked_add(dec("0.000000000000000001")),
+        Err(CommonError::Overflow(_))
+    ));
+    assert!(matches!(
+        (-max).checked_sub(dec("1")),
+        Err(CommonError::Overflow(_))
+    ));
+}
+
+#[test]
+fn test_checked_mul_div() {
+    let dec = |s: &str| SignedDecimal::from_str(s).unwrap();
+    let max = SignedDecimal::from(Decimal256::MAX);
+
+    assert_eq!(dec("-1.5").checked_mul(dec("2")).unwrap(), dec("-3"));
+    assert_eq!(
+        dec("-0.000000000000000001")
+            .checked_mul(dec("0.5"))
+            .unwrap(),
+        dec("0")
+    );
+    assert!(
+        dec("-0.000000000000000001")
+            .checked_mul(dec("0.5"))
+            .unwrap()
+            .is_positive
+    );
+    assert_eq!(max.checked_mul(dec("-1")).unwrap(), -max);
+    assert!(matches!(
+        max.checked_mul(dec("-1.000000000000000001")),
+        Err(CommonError::Overflow(_))
+    ));
+
+    assert_eq!(
+        dec("1").checked_div(dec("-3")).unwrap(),
+        dec("-0.333333333333333333")
+    );
+    assert_eq!(dec("-4.5").checked_div(dec("-1.5")).unwrap(), dec("3"));
+    assert!(matches!(
+        dec("1").checked_div(dec("0")),
+        Err(CommonError::DivideByZero(_))
+    ));
+    assert!(matches!(
+        max.checked_div(dec("0.5")),
+        Err(CommonError::Overflow(_))
+    ));
+
+    let int = |s: &str| SignedInt::from_str(s).unwrap();
+    assert!(matches!(
+        int("1").checked_div(int("0")),
+        Err(CommonError::DivideByZero(_))
+    ));
+    assert!(matches!(
+        SignedInt::MIN.checked_mul(int("2")),
+        Err(CommonError::Overflow(_))
+    ));
+}
+
+#[test]
+fn test_euclid() {
+    let dec = |s: &str| SignedDecimal::from_str(s).unwrap();
+
+    for (a, b, q, r) in [
+        ("7.5", "2", "3", "1.5"),
+        ("-7.5", "2", "-4", "0.5"),
+        ("7.5", "-2", "-3", "1.5"),
+        ("-7.5", "-2", "4", "0.5"),
+        ("-0.5", "0.25", "-2", "0"),
+        ("-0.1", "8", "-1", "7.9"),
+    ] {
+        assert_eq!(dec(a).checked_div_euclid(dec(b)).unwrap(), dec(q));
+        assert_eq!(dec(a).checked_rem_euclid(dec(b)).unwrap(), dec(r));
+        #[cfg(not(feature = "no-panic"))]
+        {
+            use num_traits::Euclid;
+
+            assert_eq!(Euclid::div_euclid(&dec(a), &dec(b)), dec(q));
+            assert_eq!(Euclid::rem_euclid(&dec(a), &dec(b)), dec(r));
+        }
+    }
+    assert!(dec("1").checked_div_euclid(dec("0")).is_err());
+    assert!(SignedDecimal::MAX.checked_div_euclid(dec("0.5")).is_err());
+}
+
+#[test]
+#[cfg(not(feature = "no-panic"))]
+fn test_signed_trait() {
+    use num_traits::Signed;
+
+    let dec = |s: &str| SignedDecimal::from_str(s).unwrap();
+
+    assert_eq!(dec("-7.5").signum(), dec("-1"));
+    assert_eq!(dec("0.5").signum(), dec("1"));
+    assert_eq!(dec("0").signum(), dec("0"));
+    assert!(!dec("0").is_positive() && !dec("0").is_negative());
+    assert!(!dec("-0").is_positive() && !dec("-0").is_negative());
+    assert!(dec("3").is_positive() && dec("-3").is_negative());
+
+    // The positive difference, clamped at zero and at MAX
+    assert_eq!(dec("7.5").abs_sub(&dec("-2")), dec("9.5"));
+    assert_eq!(dec("-2").abs_sub(&dec("7.5")), dec("0"));
+    assert_eq!(dec("4").abs_sub(&dec("4")), dec("0"));
+    assert_eq!(
+        SignedDecimal::MAX.abs_sub(&SignedDecimal::MIN),
+        SignedDecimal::MAX
+    );
+}
+
+#[test]
+fn test_cmp_abs() {
+    let dec = |s: &str| SignedDecimal::from_str(s).unwrap();
+
+    assert_eq!(dec("-3").cmp_abs(&dec("2")), Ordering::Greater);
+    assert_eq!(dec("1.5").cmp_abs(&dec("-1.6")), Ordering::Less);
+    assert!(dec("-1.5").eq_abs(&dec("1.50")));
+    assert!(!dec("-1.5").eq_abs(&dec("1.6")));
+}
+
+#[test]
+#[cfg(not(feature = "no-panic"))]
+fn test_rem() {
+    let dec = |s: &str| SignedDecimal::from_str(s).unwrap();
+
+    assert_eq!(dec("7.5") % dec("2"), dec("1.5"));
+    assert_eq!(dec("-7.5") % dec("2"), dec("-1.5"));
+    assert_eq!(dec("7.5") % dec("-2"), dec("1.5"));
+    assert!(!(dec("-4") % dec("2")).is_negative());
+    assert!(matches!(
+        dec("1").checked_rem(dec("0")),
+        Err(CommonError::DivideByZero(_))
+    ));
+}
+
+#[test]
+fn test_checked_neg_abs() {
+    let dec = |s: &str| SignedDecimal::from_str(s).unwrap();
+
+    assert_eq!(dec("1.5").checked_neg().unwrap(), dec("-1.5"));
+    assert_eq!(dec("-1.5").checked_abs().unwrap(), dec("1.5"));
+    assert!(dec("0").checked_neg().unwrap().is_positive);
+    assert!((-dec("-0")).is_positive);
+}
+
+#[test]
+fn test_mul_pow10() {
+    let dec = |s: &str| SignedDecimal::from_str(s).unwrap();
+
+    assert_eq!(dec("-1.5").mul_pow10(6).unwrap(), dec("-1500000"));
+    assert_eq!(dec("-1500000").div_pow10(6).unwrap(), dec("-1.5"));
+    assert_eq!(
+        dec("1.5").mul_pow10(-18).unwrap(),
+        dec("0.000000000000000001")
+    );
+    assert_eq!(dec("-1.5").mul_pow10(-19).unwrap(), dec("0"));
+    assert!(dec("-1.5").mul_pow10(-19).unwrap().is_positive);
+    assert!(SignedDecimal::MAX.mul_pow10(1).is_err());
+    assert_eq!(dec("0").mul_pow10(1000).unwrap(), dec("0"));
+}
+
+#[test]
+#[cfg(not(feature = "no-panic"))]
+fn test_strict_ops() {
+    use std::panic::catch_unwind;
+
+    let dec = |s: &str| SignedDecimal::from_str(s).unwrap();
+
+    assert_eq!(dec("1.5").strict_add(dec("-2")), dec("1.5") + dec("-2"));
+    assert_eq!(dec("-1.5").strict_mul(dec("2")), dec("-3"));
+    assert_eq!(dec("-3").strict_div(dec("2")), dec("-1.5"));
+    assert!(catch_unwind(|| SignedDecimal::MAX.strict_add(dec("1"))).is_err());
+    assert!(catch_unwind(|| SignedDecimal::MIN.strict_sub(dec("1"))).is_err());
+    assert!(catch_unwind(|| SignedDecimal::MAX.strict_mul(dec("2"))).is_err());
+    assert!(catch_unwind(|| dec("1").strict_div(dec("0"))).is_err());
+    // The operator keeps returning zero
+    assert_eq!(dec("1") / dec("0"), dec("0"));
+}
+
+#[test]
+fn test_saturating() {
+    let dec = |s: &str| SignedDecimal::from_str(s).unwrap();
+
+    assert_eq!(
+        SignedDecimal::MAX.saturating_add(dec("1")),
+        SignedDecimal::MAX
+    );
+    assert_eq!(
+        SignedDecimal::MIN.saturating_sub(dec("1")),
+        SignedDecimal::MIN
+    );
+    assert_eq!(
+        SignedDecimal::MIN.saturating_sub(dec("-1")),
+        SignedDecimal::MIN.checked_add(dec("1")).unwrap()
+    );
+    assert_eq!(
+        SignedDecimal::MAX.saturating_mul(dec("-2")),
+        SignedDecimal::MIN
+    );
+    assert_eq!(dec("-1.5").saturating_mul(dec("2")), dec("-3"));
+    assert_eq!(
+        SignedDecimal::MIN.saturating_div(dec("-0.5")),
+        SignedDecimal::MAX
+    );
+    assert_eq!(dec("-1").saturating_div(dec("0")), SignedDecimal::MIN);
+    assert_eq!(dec("1").saturating_div(dec("-0")), SignedDecimal::MAX);
+    assert_eq!(dec("0").saturating_div(dec("0")), dec("0"));
+    assert_eq!(dec("3").saturating_div(dec("-2")), dec("-1.5"));
+}
+
+#[test]
+fn test_overflowing() {
+    let dec = |s: &str| SignedDecimal::from_str(s).unwrap();
+
+    assert_eq!(dec("1.5").overflowing_add(dec("-2")), (dec("-0.5"), false));
+    assert_eq!(
+        SignedDecimal::MAX.overflowing_add(dec("1")),
+        (SignedDecimal::MAX, true)
+    );
+    assert_eq!(
+        SignedDecimal::MIN.overflowing_sub(dec("1")),
+        (SignedDecimal::MIN, true)
+    );
+    assert_eq!(
+        SignedDecimal::MIN.overflowing_mul(dec("1.5")),
+        (SignedDecimal::MIN, true)
+    );
+    assert_eq!(dec("-2").overflowing_mul(dec("-2")), (dec("4"), false));
+}
+
+#[test]
+fn test_tuple_conversions() {
+    let dec = |s: &str| SignedDecimal::from_str(s).unwrap();
+    let half = Decimal256::percent(50);
+
+    assert_eq!(SignedDecimal::from((half, false)), dec("-0.5"));
+    assert_eq!(dec("-0.5").as_tuple(), (half, false));
+    let (value, is_positive) = dec("0.5").as_tuple();
+    assert_eq!(SignedDecimal::from((value, is_positive)), dec("0.5"));
+    assert_eq!(
+        SignedDecimal::from((Decimal256::zero(), false)).as_tuple(),
+        (Decimal256::zero(), true)
+    );
+}
+
+#[test]
+fn test_add_sub_int() {
+    let dec = |s: &str| SignedDecimal::from_str(s).unwrap();
+    let int = |s: &str| SignedInt::from_str(s).unwrap();
+
+    #[cfg(not(feature = "no-panic"))]
+    {
+        assert_eq!(dec("1.5") + int("-3"), dec("-1.5"));
+        assert_eq!(dec("-1.5") - int("-3"), dec("1.5"));
+        assert_eq!(dec("3") - int("3"), SignedDecimal::zero());
+    }
+    assert_eq!(
+        dec("1.5")
+            .checked_add_int(int("-3"), IntScale::Atomics)
+            .unwrap(),
+        dec("1.499999999999999997")
+    );
+    assert_eq!(
+        dec("0")
+            .checked_sub_int(int("2"), IntScale::Atomics)
+            .unwrap(),
+        dec("-0.000000000000000002")
+    );
+    assert!(dec("1")
+        .checked_add_int(SignedInt::nan(), IntScale::Whole)
+        .is_err());
+    assert!(dec("1")
+        .checked_add_int(SignedInt::MAX, IntScale::Whole)
+        .is_err());
+    assert!(SignedDecimal::from(Decimal256::MAX)
+        .checked_add_int(int("1"), IntScale::Atomics)
+        .is_err());
+}
+
+#[test]
+fn test_order_of_magnitude() {
+    let mag = |s: &str| SignedDecimal::from_str(s).unwrap().order_of_magnitude();
+    assert_eq!(mag("-150"), Some(2));
+    assert_eq!(mag("100"), Some(2));
+    assert_eq!(mag("99.99"), Some(1));
+    assert_eq!(mag("1"), Some(0));
+    assert_eq!(mag("-0.0012"), Some(-3));
+    assert_eq!(mag("0.000000000000000001"), Some(-18));
+    assert_eq!(mag("0"), None);
+    assert_eq!(
+        SignedDecimal::from(Decimal256::MAX).order_of_magnitude(),
+        Some(59)
+    );
+}
+
+#[test]
+fn test_to_i64_saturating() {
+    let x = SignedDecimal::from_str("-1234.5678").unwrap();
+    assert_eq!(x.to_i64_saturating(0), -1234);
+    assert_eq!(x.to_i64_saturating(2), -123456);
+    assert_eq!(x.to_i64_saturating(-2), -12);
+    assert_eq!(x.to_i64_saturating(30), i64::MIN);
+    assert_eq!((-x).to_i64_saturating(30), i64::MAX);
+    assert_eq!(SignedDecimal::zero().to_i64_saturating(6), 0);
+    assert_eq!(SignedDecimal::zero().to_i64_saturating(96), 0);
+    assert_eq!(SignedDecimal::zero().to_i64_saturating(i32::MAX), 0);
+}
+
+#[test]
+fn test_comparison_laws() {
+    let negative_zero = SignedDecimal {
+        value: Decimal256::zero(),
+        is_positive: false,
+    };
+    let values: Vec<SignedDecimal> = [
+        "-100",
+        "-1.5",
+        "-0.000000000000000001",
+        "0",
+        "-0",
+        "0.5",
+        "100",
+    ]
+    .iter()
+    .map(|s| SignedDecimal::from_str(s).unwrap())
+    .chain([negative_zero, SignedDecimal::zero()])
+    .collect();
+
+    assert!(SignedDecimal::from_str("-0").unwrap().is_positive);
+    #[cfg(not(feature = "no-panic"))]
+    assert!((SignedDecimal::from_str("-5").unwrap() * Decimal256::zero()).is_positive);
+
+    for a in &values {
+        assert_eq!(a, a);
+        assert_eq!(a.partial_cmp(a), Some(std::cmp::Ordering::Equal));
+        for b in &values {
+            assert_eq!(a == b, b == a);
+            assert_eq!(a == b, a.partial_cmp(b) == Some(std::cmp::Ordering::Equal));
+            assert_eq!(a.partial_cmp(b), b.partial_cmp(a).map(|o| o.reverse()));
+            if a <= b && b <= a {
+                assert_eq!(a, b);
+            }
+            for c in &values {
+                if a <= b && b <= c {
+                    assert!(a <= c);
+                }
+                if a == b && b == c {
+                    assert_eq!(a, c);
+                }
+            }
+        }
+    }
+
+    assert!(negative_zero.eq_decimal(&Decimal256::zero()));
+    assert!(SignedDecimal::zero().eq_decimal(&Decimal256::zero()));
+    assert!(SignedDecimal::from_str("0.5")
+        .unwrap()
+        .eq_decimal(&Decimal256::percent(50)));
+    assert!(!SignedDecimal::from_str("-0.5")
+        .unwrap()
+        .eq_decimal(&Decimal256::percent(50)));
+}
+
+#[test]
+#[cfg(not(feature = "no-panic"))]
+fn test_snapshot_history() {
+    use cosmwasm_std::testing::MockStorage;
+    use cw_storage_plus::{SnapshotItem, Strategy};
+
+    const FUNDING: SnapshotItem<SignedDecimal> = SnapshotItem::new(
+        "funding",
+        "funding__check",
+        "funding__change",
+        Strategy::EveryBlock,
+    );
+
+    let mut storage = MockStorage::new();
+    let first = SignedDecimal::from_str("-0.25").unwrap();
+    let second = SignedDecimal::from_str("0.5").unwrap();
+    FUNDING.save(&mut storage, &first, 10).unwrap();
+    FUNDING.save(&mut storage, &second, 20).unwrap();
+
+    let old = FUNDING.may_load_at_height(&storage, 20).unwrap().unwrap();
+    assert_eq!(old, first);
+    let current = FUNDING.load(&storage).unwrap();
+    assert_eq!(
+        current.delta_since(&old),
+        SignedDecimal::from_str("0.75").unwrap()
+    );
+    assert_eq!(
+        old.delta_since(&current),
+        SignedDecimal::from_str("-0.75").unwrap()
+    );
+}
+
+#[cfg(all(test, not(feature = "no-panic")))]
+crate::signed_arith_laws!(arith_laws, SignedDecimal);
+
+#[test]
+fn test_from_uint_with_scale() {
+    assert_eq!(
+        SignedDecimal::from_uint256_with_scale(Uint256::from_u128(1_500_000), 6).unwrap(),
+        SignedDecimal::from_str("1.5").unwrap()
+    );
+    assert_eq!(
+        SignedDecimal::from_uint256_with_scale(Uint256::from_u128(123), 20).unwrap(),
+        SignedDecimal::from_str("0.000000000000000001").unwrap()
+    );
+    assert_eq!(
+        SignedDecimal::from_uint256_with_scale(Uint256::MAX, 6),
+        Err(CommonError::ScaledValueOutOfRange {
+            value: Uint256::MAX.to_string(),
+            decimal_places: 6,
+        })
+    );
+    assert!(SignedDecimal::from_uint256(Uint256::MAX).is_err());
+    assert_eq!(
+        SignedDecimal::from_uint128(Uint128::new(42)),
+        SignedDecimal::from_str("42").unwrap()
+    );
+    assert_eq!(
+        SignedDecimal::from_uint128(Uint128::MAX).to_string(),
+        u128::MAX.to_string()
+    );
+}
+
+#[test]
+fn test_zero_comparisons() {
+    let neg = SignedDecimal::from_str("-0.5").unwrap();
+    let zero = SignedDecimal::zero();
+    let pos = SignedDecimal::from_str("0.5").unwrap();
+
+    assert!(!neg.is_gt_zero() && !zero.is_gt_zero() && pos.is_gt_zero());
+    assert!(neg.is_lt_zero() && !zero.is_lt_zero() && !pos.is_lt_zero());
+    assert!(!neg.is_gte_zero() && zero.is_gte_zero() && pos.is_gte_zero());
+    assert!(neg.is_lte_zero() && zero.is_lte_zero() && !pos.is_lte_zero());
+
+    assert!(zero.is_within(&(neg..=pos)));
+    assert!(neg.is_within(&(neg..pos)));
+    assert!(!pos.is_within(&(neg..pos)));
+    assert!(pos.is_within(&(zero..)));
+    assert!(!neg.is_within(&(zero..)));
+}
+
+#[test]
+fn test_percent_of() {
+    let dec = |s: &str| SignedDecimal::from_str(s).unwrap();
+    let int = |s: &str| SignedInt::from_str(s).unwrap();
+    let base = Uint256::from_u128(1005);
+
+    // 2.5% of 1005 is 25.125
+    let fee = dec("0.025");
+    assert_eq!(
+        fee.percent_of(base, RoundingMode::Floor).unwrap(),
+        int("25")
+    );
+    assert_eq!(fee.percent_of(base, RoundingMode::Ceil).unwrap(), int("26"));
+    assert_eq!(
+        fee.percent_of(base, RoundingMode::HalfUp).unwrap(),
+        int("25")
+    );
+
+    // -2.5% of 1005 is -25.125
+    let rebate = dec("-0.025");
+    assert_eq!(
+        rebate.percent_of(base, RoundingMode::Floor).unwrap(),
+        int("-26")
+    );
+    assert_eq!(
+        rebate.percent_of(base, RoundingMode::Ceil).unwrap(),
+        int("-25")
+    );
+    assert_eq!(
+        rebate.percent_of(base, RoundingMode::TowardZero).unwrap(),
+        int("-25")
+    );
+    assert_eq!(
+        rebate.percent_of(base, RoundingMode::AwayFromZero).unwrap(),
+        int("-26")
+    );
+    assert_eq!(
+        dec("-0.5")
+            .percent_of(Uint256::from_u128(5), RoundingMode::HalfUp)
+            .unwrap(),
+        int("-3")
+    );
+    assert_eq!(
+        rebate
+            .percent_of(Uint256::from_u128(1), RoundingMode::Ceil)
+            .unwrap(),
+        SignedInt::zero()
+    );
+
+    // 0.000000000000000003 * -0.5 is -0.0000000000000000015
+    let tiny = dec("0.000000000000000003");
+    assert_eq!(
+        tiny.of(dec("-0.5"), RoundingMode::Floor).unwrap(),
+        dec("-0.000000000000000002")
+    );
+    assert_eq!(
+        tiny.of(dec("-0.5"), RoundingMode::Ceil).unwrap(),
+        dec("-0.000000000000000001")
+    );
+    assert_eq!(
+        tiny.of(dec("-0.5"), RoundingMode::HalfUp).unwrap(),
+        dec("-0.000000000000000002")
+    );
+    assert_eq!(
+        dec("-0.1").of(dec("-30"), RoundingMode::Floor).unwrap(),
+        dec("3")
+    );
+
+    assert!(matches!(
+        dec("2").percent_of(Uint256::MAX, RoundingMode::Floor),
+        Err(CommonError::ConversionOverflow(_))
+    ));
+}
+
+#[test]
+fn test_fallible_accessors() {
+    let x = SignedDecimal::from_str("-2.5").unwrap();
+    assert!(x.try_value().is_err());
+    assert_eq!(x.abs_value(), Decimal256::from_str("2.5").unwrap());
+    assert_eq!(
+        (-x).try_value().unwrap(),
+        Decimal256::from_str("2.5").unwrap()
+    );
+
+    // Empty input is an error rather than a panic
+    assert!(SignedDecimal::from_str("").is_err());
+    assert!(SignedInt::from_str("").is_err());
+    assert!(SignedDecimal::from_str("-").is_err());
+}
+
+#[test]
+fn test_optional_fields() {
+    let dec = |s: &str| SignedDecimal::from_str(s).unwrap();
+
+    assert_eq!(SignedDecimal::opt_from_str(None).unwrap(), None);
+    assert_eq!(
+        SignedDecimal::opt_from_str(Some("-1.5")).unwrap(),
+        Some(dec("-1.5"))
+    );
+    assert!(matches!(
+        SignedDecimal::opt_from_str(Some("1.5.5")),
+        Err(CommonError::Parse(_))
+    ));
+
+    assert_eq!(SignedDecimal::or_zero(None), SignedDecimal::zero());
+    assert_eq!(SignedDecimal::or_zero(Some(dec("-2"))), dec("-2"));
+}
+
+#[test]
+fn test_from_int() {
+    let dec = |s: &str| SignedDecimal::from_str(s).unwrap();
+
+    assert_eq!(SignedDecimal::from_int(-3), dec("-3"));
+    assert_eq!(SignedDecimal::from_int(0u8), SignedDecimal::zero());
+    assert!(SignedDecimal::from_int(0).is_positive);
+    assert_eq!(
+        SignedDecimal::from(i128::MIN),
+        dec("-170141183460469231731687303715884105728")
+    );
+    assert_eq!(SignedDecimal::from(-5i64), dec("-5"));
+    assert_eq!(SignedDecimal::from(u64::MAX), dec("18446744073709551615"));
+}
+
+#[test]
+fn test_total_order() {
+    use std::collections::BTreeMap;
+
+    let dec = |s: &str| SignedDecimal::from_str(s).unwrap();
+    let negative_zero = SignedDecimal {
+        value: Decimal256::zero(),
+        is_positive: false,
+    };
+    let values = [
+        SignedDecimal::MIN,
+        dec("-1"),
+        dec("-0.000000000000000001"),
+        negative_zero,
+        SignedDecimal::zero(),
+        dec("0.000000000000000001"),
+        dec("1"),
+        SignedDecimal::MAX,
+    ];
+    // Indices 3 and 4 are both zero
+    let is_zero = |k: usize| k == 3 || k == 4;
+    for (i, a) in values.iter().enumerate() {
+        for (j, b) in values.iter().enumerate() {
+            let expected = if is_zero(i) && is_zero(j) {
+                Ordering::Equal
+            } else {
+                i.cmp(&j)
+            };
+            assert_eq!(a.cmp(b), expected, "{i} vs {j}");
+            assert_eq!(a.partial_cmp(b), Some(a.cmp(b)));
+            assert_eq!(a == b, a.cmp(b) == Ordering::Equal);
+        }
+    }
+
+    let mut map = BTreeMap::new();
+    map.insert(SignedDecimal::zero(), "zero");
+    map.insert(negative_zero, "negative zero");
+    assert_eq!(map.len(), 1);
+    assert_eq!(map[&SignedDecimal::zero()], "negative zero");
+}
+
+#[test]
+fn test_atomics_primitives() {
+    let dec = |s: &str| SignedDecimal::from_str(s).unwrap();
+
+    assert_eq!(
+        dec("-1.5").atomics_i128().unwrap(),
+        -1_500_000_000_000_000_000
+    );
+    assert_eq!(
+        dec("-1.5").atomics_i64().unwrap(),
+        -1_500_000_000_000_000_000
+    );
+    assert_eq!(SignedDecimal::zero().atomics_i64().unwrap(), 0);
+    assert!(dec("-10").atomics_i64().is_err());
+    assert!(SignedDecimal::MAX.atomics_i128().is_err());
+}
+
+#[test]
+fn test_f64_interop() {
+    let dec = |s: &str| SignedDecimal::from_str(s).unwrap();
+
+    assert_eq!(dec("-1.5").to_f64(), -1.5);
+    assert_eq!(dec("0.1").to_f64(), 0.1);
+    assert_eq!(SignedDecimal::MIN.to_f64(), -1.157920892373162e59);
+
+    assert_eq!(SignedDecimal::try_from_f64(0.1).unwrap(), dec("0.1"));
+    assert_eq!(SignedDecimal::try_from_f64(-2.75).unwrap(), dec("-2.75"));
+    assert_eq!(
+        SignedDecimal::try_from_f64(1e-18).unwrap(),
+        dec("0.000000000000000001")
+    );
+    // Below half an atomic unit rounds to an unsigned zero
+    let zero = SignedDecimal::try_from_f64(-4e-19).unwrap();
+    assert!(zero.is_zero() && zero.is_positive);
+    assert_eq!(
+        SignedDecimal::try_from_f64(1e40).unwrap(),
+        dec(&format!("1{}", "0".repeat(40)))
+    );
+
+    for v in [f64::NAN, f64::INFINITY, f64::NEG_INFINITY, 1e60, -1e300] {
+        assert!(matches!(
+            SignedDecimal::try_from_f64(v),
+            Err(CommonError::ConversionOverflow(_))
+        ));
+    }
+}
+
+#[test]
+fn test_primitive_traits() {
+    let dec = |s: &str| SignedDecimal::from_str(s).unwrap();
+
+    assert_eq!(dec("-7.9").to_i64(), Some(-7));
+    assert_eq!(dec("-0.5").to_u64(), Some(0));
+    assert_eq!(dec("-1.5").to_u64(), None);
+    assert_eq!(dec("2.5").to_u8(), Some(2));
+    assert_eq!(SignedDecimal::MAX.to_i128(), None);
+    assert_eq!(ToPrimitive::to_f64(&dec("-2.25")), Some(-2.25));
+
+    assert_eq!(SignedDecimal::from_i32(-3), Some(dec("-3")));
+    assert_eq!(
+        SignedDecimal::from_u128(u128::MAX),
+        Some(dec("340282366920938463463374607431768211455"))
+    );
+    assert_eq!(SignedDecimal::from_f32(0.1), Some(dec("0.1")));
+    assert_eq!(SignedDecimal::from_f64(-0.1), Some(dec("-0.1")));
+    assert_eq!(SignedDecimal::from_f64(f64::INFINITY), None);
+}
+
+#[test]
+fn test_signed_int_conversions() {
+    let dec = |s: &str| SignedDecimal::from_str(s).unwrap();
+    let int = |s: &str| SignedInt::from_str(s).unwrap();
+
+    assert_eq!(SignedDecimal::try_from(int("-42")).unwrap(), dec("-42"));
+    assert_eq!(SignedDecimal::try_from(int("-0")).unwrap(), dec("0"));
+    assert_eq!(
+        SignedDecimal::try_from(SignedDecimal::MIN.to_signed_int_trunc()).unwrap(),
+        dec("-115792089237316195423570985008687907853269984665640564039457")
+    );
+    assert!(matches!(
+        SignedDecimal::try_from(SignedInt::MIN),
+        Err(CommonError::ConversionOverflow(_))
+    ));
+    assert!(SignedDecimal::try_from(SignedInt::nan()).is_err());
+
+    assert_eq!(dec("-1.5").to_signed_int_floor(), int("-2"));
+    assert_eq!(dec("-1.5").to_signed_int_ceil(), int("-1"));
+    assert_eq!(dec("-1.5").to_signed_int_trunc(), int("-1"));
+    assert_eq!(dec("1.5").to_signed_int_floor(), int("1"));
+    assert_eq!(dec("1.5").to_signed_int_ceil(), int("2"));
+    assert_eq!(dec("-0.5").to_signed_int_ceil(), SignedInt::zero());
+    assert_eq!(dec("-3").to_signed_int_floor(), int("-3"));
+    assert_eq!(
+        SignedDecimal::MAX.to_signed_int_ceil(),
+        int("115792089237316195423570985008687907853269984665640564039458")
+    );
+}
+
+#[test]
+fn test_to_int_with() {
+    let dec = |s: &str| SignedDecimal::from_str(s).unwrap();
+    let int = |s: &str| SignedInt::from_str(s).unwrap();
+
+    let cases = [
+        ("-1.5", "-2", "-1", "-1", "-2"),
+        ("-1.4", "-2", "-1", "-1", "-1"),
+        ("1.5", "1", "2", "1", "2"),
+        ("2.4", "2", "3", "2", "2"),
+        ("-0.2", "-1", "0", "0", "0"),
+        ("-3", "-3", "-3", "-3", "-3"),
+    ];
+    for (x, floor, ceil, toward_zero, half_up) in cases {
+        let x = dec(x);
+        assert_eq!(x.to_int_with(RoundingMode::Floor), int(floor));
+        assert_eq!(x.to_int_with(RoundingMode::Ceil), int(ceil));
+        assert_eq!(x.to_int_with(RoundingMode::TowardZero), int(toward_zero));
+        assert_eq!(x.to_int_with(RoundingMode::HalfUp), int(half_up));
+    }
+    assert_eq!(
+        dec("-1.2").to_int_with(RoundingMode::AwayFromZero),
+        int("-2")
+    );
+
+    assert_eq!(SignedInt::try_from(dec("-42")).unwrap(), int("-42"));
+    assert_eq!(SignedInt::try_from(dec("0")).unwrap(), SignedInt::zero());
+    assert!(SignedInt::try_from(dec("-42.5")).is_err());
+    assert!(SignedInt::try_from(dec("0.000000000000000001")).is_err());
+}
+
+#[test]
+fn test_to_int_toward_and_away_from_zero() {
+    let dec = |s: &str| SignedDecimal::from_str(s).unwrap();
+    let int = |s: &str| SignedInt::from_str(s).unwrap();
+
+    let cases = [
+        ("-1.5", "-1", "-2"),
+        ("-1.2", "-1", "-2"),
+        ("1.2", "1", "2"),
+        ("-0.000000000000000001", "0", "-1"),
+        ("7", "7", "7"),
+        ("0", "0", "0"),
+    ];
+    for (x, toward, away) in cases {
+        let x = dec(x);
+        assert_eq!(x.to_int_toward_zero(), int(toward));
+        assert_eq!(x.to_int_away_from_zero(), int(away));
+        // Unlike floor and ceil, negating commutes with both
+        assert_eq!((-x).to_int_toward_zero(), -int(toward));
+        assert_eq!((-x).to_int_away_from_zero(), -int(away));
+    }
+    assert!(!dec("-0.5").to_int_toward_zero().is_lt_zero());
+}
+
+#[test]
+fn test_div_exact() {
+    let dec = |s: &str| SignedDecimal::from_str(s).unwrap();
+
+    assert_eq!(dec("-3").div_exact(dec("2")).unwrap(), dec("-1.5"));
+    assert_eq!(dec("1").div_exact(dec("-8")).unwrap(), dec("-0.125"));
+    assert_eq!(dec("0").div_exact(dec("7")).unwrap(), dec("0"));
+    assert_eq!(
+        dec("0.000000000000000001").div_exact(dec("0.5")).unwrap(),
+        dec("0.000000000000000002")
+    );
+    assert!(matches!(
+        dec("1").div_exact(dec("3")),
+        Err(CommonError::Generic(_))
+    ));
+    assert!(dec("0.000000000000000001").div_exact(dec("2")).is_err());
+    assert!(matches!(
+        dec("1").div_exact(dec("0")),
+        Err(CommonError::DivideByZero(_))
+    ));
+    assert!(SignedDecimal::MAX.div_exact(dec("0.5")).is_err());
+}
+
+#[test]
+fn test_sdk_dec_str() {
+    let dec = |s: &str| SignedDecimal::from_str(s).unwrap();
+
+    assert_eq!(
+        SignedDecimal::from_sdk_dec_str("-1500000000000000000").unwrap(),
+        dec("-1.5")
+    );
+    assert_eq!(
+        SignedDecimal::from_sdk_dec_str("1").unwrap(),
+        dec("0.000000000000000001")
+    );
+    assert_eq!(SignedDecimal::from_sdk_dec_str("-0").unwrap(), dec("0"));
+    assert_eq!(dec("-1.5").to_sdk_dec_str(), "-1500000000000000000");
+    assert_eq!(dec("0").to_sdk_dec_str(), "0");
+    for x in [SignedDecimal::MIN, SignedDecimal::MAX, dec("-0.25")] {
+        assert_eq!(
+            SignedDecimal::from_sdk_dec_str(&x.to_sdk_dec_str()).unwrap(),
+            x
+        );
+    }
+
+    assert!(matches!(
+        SignedDecimal::from_sdk_dec_str("1.5"),
+        Err(CommonError::Parse(_))
+    ));
+    assert!(SignedDecimal::from_sdk_dec_str("").is_err());
+}
+
+#[test]
+#[cfg(not(feature = "no-panic"))]
+fn test_from_str_radix() {
+    assert_eq!(
+        SignedDecimal::from_str_radix("-1.25", 10).unwrap(),
+        SignedDecimal::from_str("-1.25").unwrap()
+    );
+    assert_eq!(
+        SignedDecimal::from_str_radix("1.0000000000000000001", 10),
+        Err(ParseSignedError::TooManyFractionalDigits { got: 19, max: 18 })
+    );
+    assert_eq!(
+        SignedDecimal::from_str_radix("ff", 16),
+        Err(ParseSignedError::InvalidRadix { radix: 16 })
+    );
+}
@@ -1,24 +1,43 @@
 use std::{
+    cmp::Ordering,
     convert::{TryFrom, TryInto},
-    ops::{Neg, Rem},
+    fmt,
+    ops::{Neg, RangeBounds},
     str::FromStr,
 };
 
-use cosmwasm_std::{Decimal256, Uint256};
-use num_traits::{Num, One, Zero};
+use cosmwasm_std::{
+    ConversionOverflowError, DivideByZeroError, OverflowError, OverflowOperation, Uint128, Uint256,
+    Uint512,
+};
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
-use crate::error::CommonError;
+use crate::{
+    error::{CommonError, CommonResult, ParseSignedError, MAX_PARSE_LEN},
+    signed_decimal::RoundingMode,
+    signed_int512::SignedInt512,
+};
 
 /// Uint256 with a sign
 #[derive(Serialize, Deserialize, Clone, Copy, Debug, JsonSchema)]
+#[cfg_attr(feature = "python", pyo3::pyclass(module = "signed_decimal_prototype"))]
 pub struct SignedInt {
     pub value: Uint256,
     pub is_positive: bool,
 }
 
 impl SignedInt {
+    pub const MAX: Self = Self {
+        value: Uint256::MAX,
+        is_positive: true,
+    };
+
+    pub const MIN: Self = Self {
+        value: Uint256::MAX,
+        is_positive: false,
+    };
+
     pub const fn nan() -> Self {
         Self {
             value: Uint256::zero(),
@@ -30,35 +49,673 @@ impl SignedInt {
         self.value.is_zero() && !self.is_positive
     }
 
+    #[cfg(not(feature = "no-panic"))]
     pub fn value(&self) -> Uint256 {
         assert!(self.is_positive, "SignedInt is negative!");
         self.value
     }
-}
 
-impl Neg for SignedInt {
-    type Output = Self;
+    pub const fn zero() -> Self {
+        Self {
+            value: Uint256::zero(),
+            is_positive: true,
+        }
+    }
 
-    fn neg(self) -> Self::Output {
-        if self.is_zero() {
-            return self;
+    pub const fn one() -> Self {
+        Self::from_u128(1)
+    }
+
+    pub const fn from_u128(num: u128) -> Self {
+        Self {
+            value: Uint256::from_u128(num),
+            is_positive: true,
         }
+    }
+
+    pub const fn is_zero(&self) -> bool {
+        self.value.is_zero()
+    }
+
+    /// Strictly greater than zero, like `Signed::is_positive`. False for NaN,
+    /// as are the other zero comparisons.
+    pub fn is_gt_zero(&self) -> bool {
+        self.is_positive && !self.value.is_zero()
+    }
+
+    /// Strictly less than zero
+    pub fn is_lt_zero(&self) -> bool {
+        !self.is_positive && !self.value.is_zero()
+    }
+
+    /// Zero or greater
+    pub fn is_gte_zero(&self) -> bool {
+        self.is_positive
+    }
+
+    /// Zero or less
+    pub fn is_lte_zero(&self) -> bool {
+        !self.is_gt_zero() && !self.is_nan()
+    }
+
+    /// Whether self lies in `range`, e.g. `x.is_within(&(min..=max))`
+    pub fn is_within<R: RangeBounds<Self>>(&self, range: &R) -> bool {
+        range.contains(self)
+    }
+
+    /// Signed change from `old` to self, e.g. between two snapshots
+    #[cfg(not(feature = "no-panic"))]
+    pub fn delta_since(&self, old: &Self) -> Self {
+        *self - *old
+    }
+
+    /// The magnitude and `is_positive` flag, the inverse of
+    /// `From<(Uint256, bool)>`
+    pub const fn as_tuple(&self) -> (Uint256, bool) {
+        (self.value, self.is_positive)
+    }
+
+    /// Compares magnitudes, ignoring signs, so NaN compares like zero
+    pub fn cmp_abs(&self, other: &Self) -> Ordering {
+        self.value.cmp(&other.value)
+    }
+
+    /// Whether the magnitudes are equal, ignoring signs
+    pub fn eq_abs(&self, other: &Self) -> bool {
+        self.value == other.value
+    }
+
+    /// Whether self equals the unsigned `other`. NaN is never equal.
+    pub fn eq_uint(&self, other: &Uint256) -> bool {
+        self.is_positive && self.value == *other
+    }
+
+    /// Raises self to the power of `exp`, panicking on overflow like `Uint256::pow`
+    #[cfg(not(feature = "no-panic"))]
+    pub fn pow(self, exp: u32) -> Self {
+        self.checked_pow(exp).unwrap()
+    }
+
+    /// Returns `self * numerator / denominator`, keeping the sign of self
+    #[cfg(not(feature = "no-panic"))]
+    pub fn multiply_ratio<A: Into<Uint256>, B: Into<Uint256>>(
+        &self,
+        numerator: A,
+        denominator: B,
+    ) -> Self {
+        let value = self.value.multiply_ratio(numerator, denominator);
         Self {
+            value,
+            is_positive: self.is_positive || value.is_zero(),
+        }
+    }
+
+    /// [`SignedInt::multiply_ratio`] returning an error instead of panicking
+    /// on a zero denominator or a result that doesn't fit
+    pub fn checked_multiply_ratio<A: Into<Uint256>, B: Into<Uint256>>(
+        &self,
+        numerator: A,
+        denominator: B,
+    ) -> CommonResult<Self> {
+        let value = RoundingMode::TowardZero.div(
+            self.value.full_mul(numerator),
+            Uint512::from(denominator.into()),
+            self.is_positive,
+        )?;
+        Ok(Self {
+            value,
+            is_positive: self.is_positive || value.is_zero(),
+        })
+    }
+
+    pub fn checked_add(self, other: Self) -> CommonResult<Self> {
+        self.signed_add(other)
+            .ok_or_else(|| OverflowError::new(OverflowOperation::Add, self, other).into())
+    }
+
+    pub fn checked_sub(self, other: Self) -> CommonResult<Self> {
+        self.signed_add(-other)
+            .ok_or_else(|| OverflowError::new(OverflowOperation::Sub, self, other).into())
+    }
+
+    /// Sign-magnitude sum, or `None` on overflow. Building the error is left
+    /// to the callers so a failed op reports exactly once.
+    fn signed_add(self, other: Self) -> Option<Self> {
+        if self.is_positive != other.is_positive {
+            // Magnitudes with opposite signs shrink, so this can't overflow
+            return Some(match self.value.cmp(&other.value) {
+                Ordering::Greater => Self {
+                    value: self.value - other.value,
+                    is_positive: self.is_positive,
+                },
+                Ordering::Less => Self {
+                    value: other.value - self.value,
+                    is_positive: other.is_positive,
+                },
+                Ordering::Equal => Self::zero(),
+            });
+        }
+        let value = self.value.checked_add(other.value).ok()?;
+        Some(Self {
+            value,
+            is_positive: self.is_positive,
+        })
+    }
+
+    pub fn checked_mul(self, other: Self) -> CommonResult<Self> {
+        let value = self
+            .value
+            .checked_mul(other.value)
+            .map_err(|_| OverflowError::new(OverflowOperation::Mul, self, other))?;
+        Ok(Self {
+            value,
+            is_positive: self.is_positive == other.is_positive || value.is_zero(),
+        })
+    }
+
+    pub fn checked_div(self, other: Self) -> CommonResult<Self> {
+        let value = self
+            .value
+            .checked_div(other.value)
+            .map_err(|_| DivideByZeroError::new(self))?;
+        Ok(Self {
+            value,
+            is_positive: self.is_positive == other.is_positive || value.is_zero(),
+        })
+    }
+
+    /// Truncated remainder, the result takes the sign of self
+    pub fn checked_rem(self, other: Self) -> CommonResult<Self> {
+        let value = self
+            .value
+            .checked_rem(other.value)
+            .map_err(|_| DivideByZeroError::new(self))?;
+        Ok(Self {
+            value,
+            is_positive: self.is_positive || value.is_zero(),
+        })
+    }
+
+    /// Quotient rounded so that [`SignedInt::checked_rem_euclid`] is never
+    /// negative, i.e. toward negative infinity for a positive divisor
+    pub fn checked_div_euclid(self, other: Self) -> CommonResult<Self> {
+        let quotient = self.checked_div(other)?;
+        if !self.checked_rem(other)?.is_lt_zero() {
+            return Ok(quotient);
+        }
+        if other.is_positive {
+            quotient.checked_sub(Self::one())
+        } else {
+            quotient.checked_add(Self::one())
+        }
+    }
+
+    /// Remainder in `[0, |other|)`, erroring on a zero divisor
+    pub fn checked_rem_euclid(self, other: Self) -> CommonResult<Self> {
+        let rem = self.checked_rem(other)?;
+        if rem.is_lt_zero() {
+            rem.checked_add(Self::from(other.value))
+        } else {
+            Ok(rem)
+        }
+    }
+
+    pub fn checked_pow(self, exp: u32) -> CommonResult<Self> {
+        let value = self
+            .value
+            .checked_pow(exp)
+            .map_err(|_| OverflowError::new(OverflowOperation::Pow, self, exp))?;
+        Ok(Self {
+            value,
+            is_positive: self.is_positive || exp & 1 == 0 || value.is_zero(),
+        })
+    }
+
+    /// `self^exp mod |modulus|`, in `[0, |modulus|)`.
+    ///
+    /// Only the magnitude of the modulus matters, a negative self is reduced
+    /// like [`SignedInt::checked_rem_euclid`] first, and a negative `exp`
+    /// raises the [`SignedInt::mod_inverse`]. Errors on NaN, a zero modulus or
+    /// a missing inverse.
+    pub fn mod_pow(self, exp: Self, modulus: Self) -> CommonResult<Self> {
+        if exp.is_nan() {
+            return Err(CommonError::Generic(
+                "NaN can't be used as an exponent".to_string(),
+            ));
+        }
+        let mut base = if exp.is_positive {
+            self.mod_reduce(modulus)?
+        } else {
+            self.mod_inverse(modulus)?.value
+        };
+        let m = modulus.value;
+        let mul = |a: Uint256, b: Uint256| -> CommonResult<Uint256> {
+            Ok(Uint256::try_from(a.full_mul(b).checked_rem(m.into())?)?)
+        };
+        let mut result = Uint256::from(1u8).checked_rem(m)?;
+        let mut e = exp.value;
+        while !e.is_zero() {
+            if e.to_be_bytes()[31] & 1 == 1 {
+                result = mul(result, base)?;
+            }
+            e >>= 1;
+            if !e.is_zero() {
+                base = mul(base, base)?;
+            }
+        }
+        Ok(Self::from(result))
+    }
+
+    /// The `x` in `[0, |modulus|)` with `self * x = 1 mod |modulus|`, with the
+    /// same sign rules as [`SignedInt::mod_pow`]. Errors if self and the
+    /// modulus share a factor, so no inverse exists.
+    pub fn mod_inverse(self, modulus: Self) -> CommonResult<Self> {
+        let m = modulus.value;
+        // Extended Euclid, where the coefficients never exceed the modulus
+        let (mut old_r, mut r) = (self.mod_reduce(modulus)?, m);
+        let (mut old_s, mut s) = (Self::one(), Self::zero());
+        while !r.is_zero() {
+            let q = old_r / r;
+            (old_r, r) = (r, old_r - q * r);
+            (old_s, s) = (s, old_s.checked_sub(Self::from(q).checked_mul(s)?)?);
+        }
+        if old_r != Uint256::from(1u8) {
+            return Err(CommonError::Generic(format!(
+                "{} has no inverse modulo {}",
+                self.to_string(),
+                m
+            )));
+        }
+        old_s.checked_rem_euclid(Self::from(m))
+    }
+
+    /// Self in `[0, |modulus|)`
+    fn mod_reduce(self, modulus: Self) -> CommonResult<Uint256> {
+        if self.is_nan() || modulus.is_nan() {
+            return Err(CommonError::Generic(
+                "NaN has no value modulo an integer".to_string(),
+            ));
+        }
+        Ok(self.checked_rem_euclid(modulus)?.value)
+    }
+
+    /// Multiplies by `10^exp`, shifting the decimal point right, or divides
+    /// by `10^-exp` truncating toward zero when `exp` is negative
+    pub fn mul_pow10(self, exp: i32) -> CommonResult<Self> {
+        let value = shift_pow10(self.value, exp)
+            .ok_or_else(|| OverflowError::new(OverflowOperation::Mul, self, format!("1e{exp}")))?;
+        Ok(Self {
+            value,
+            is_positive: self.is_positive || value.is_zero(),
+        })
+    }
+
+    /// Divides by `10^exp` truncating toward zero, the inverse of
+    /// [`SignedInt::mul_pow10`]
+    pub fn div_pow10(self, exp: i32) -> CommonResult<Self> {
+        self.mul_pow10(exp.checked_neg().unwrap_or(i32::MAX))
+    }
+
+    /// Negation keeping zero positive. Errors only for NaN, which has no
+    /// sign to flip.
+    pub fn checked_neg(self) -> CommonResult<Self> {
+        if self.is_nan() {
+            return Err(CommonError::Generic("Cannot negate NaN".to_string()));
+        }
+        Ok(Self {
             value: self.value,
-            is_positive: !self.is_positive,
+            is_positive: !self.is_positive || self.value.is_zero(),
+        })
+    }
+
+    /// Magnitude as a non-negative value. Errors only for NaN.
+    pub fn checked_abs(self) -> CommonResult<Self> {
+        if self.is_nan() {
+            return Err(CommonError::Generic(
+                "Cannot take the absolute value of NaN".to_string(),
+            ));
+        }
+        Ok(Self::from(self.value))
+    }
+
+    /// Sum that panics on overflow, which the `+` operator forwards to
+    #[cfg(not(feature = "no-panic"))]
+    pub fn strict_add(self, other: Self) -> Self {
+        self.checked_add(other)
+            .expect("attempt to add with overflow")
+    }
+
+    /// Difference that panics on overflow, which the `-` operator forwards to
+    #[cfg(not(feature = "no-panic"))]
+    pub fn strict_sub(self, other: Self) -> Self {
+        self.checked_sub(other)
+            .expect("attempt to subtract with overflow")
+    }
+
+    /// Product that panics on overflow, which the `*` operator forwards to
+    #[cfg(not(feature = "no-panic"))]
+    pub fn strict_mul(self, other: Self) -> Self {
+        self.checked_mul(other)
+            .expect("attempt to multiply with overflow")
+    }
+
+    /// Quotient that panics on a zero divisor or overflow
+    #[cfg(not(feature = "no-panic"))]
+    pub fn strict_div(self, other: Self) -> Self {
+        self.checked_div(other)
+            .unwrap_or_else(|err| panic!("{err}"))
+    }
+
+    /// Sum in 512 bits, which can't overflow. Narrow the result back with
+    /// `SignedInt::try_from`.
+    pub fn wide_add(self, rhs: Self) -> SignedInt512 {
+        SignedInt512::from(self)
+            .checked_add(rhs.into())
+            .expect("two SignedInts always fit in 512 bits")
+    }
+
+    /// Difference in 512 bits, which can't overflow
+    pub fn wide_sub(self, rhs: Self) -> SignedInt512 {
+        SignedInt512::from(self)
+            .checked_sub(rhs.into())
+            .expect("two SignedInts always fit in 512 bits")
+    }
+
+    pub fn saturating_add(self, other: Self) -> Self {
+        self.checked_add(other).unwrap_or(if self.is_positive {
+            Self::MAX
+        } else {
+            Self::MIN
+        })
+    }
+
+    pub fn saturating_sub(self, other: Self) -> Self {
+        self.checked_sub(other).unwrap_or(if self.is_positive {
+            Self::MAX
+        } else {
+            Self::MIN
+        })
+    }
+
+    pub fn saturating_mul(self, other: Self) -> Self {
+        self.checked_mul(other)
+            .unwrap_or(if self.is_positive == other.is_positive {
+                Self::MAX
+            } else {
+                Self::MIN
+            })
+    }
+
+    /// Quotient truncated toward zero, where a zero divisor gives MAX or MIN
+    /// by the sign of self, or zero for zero
+    pub fn saturating_div(self, other: Self) -> Self {
+        self.checked_div(other).unwrap_or(if self.is_zero() {
+            Self::zero()
+        } else if self.is_positive {
+            Self::MAX
+        } else {
+            Self::MIN
+        })
+    }
+
+    /// Converts `self * 10^exp` into an i64 for metrics sinks, truncating toward
+    /// zero and clamping to the i64 range instead of panicking. Negative
+    /// exponents divide.
+    pub fn to_i64_saturating(&self, exp: i32) -> i64 {
+        // Zero stays zero however large the scale, which may not fit itself
+        if self.value.is_zero() {
+            return 0;
+        }
+        let ten = Uint256::from_u128(10);
+        let magnitude = if exp >= 0 {
+            ten.checked_pow(exp.unsigned_abs())
+                .and_then(|scale| self.value.checked_mul(scale))
+                .unwrap_or(Uint256::MAX)
+        } else {
+            ten.checked_pow(exp.unsigned_abs())
+                .map(|scale| self.value / scale)
+                .unwrap_or_else(|_| Uint256::zero())
+        };
+        let magnitude = Uint128::try_from(magnitude).map_or(u128::MAX, |m| m.u128());
+        let magnitude = i128::try_from(magnitude).unwrap_or(i128::MAX);
+        let signed = if self.is_positive {
+            magnitude
+        } else {
+            -magnitude
+        };
+        signed.clamp(i64::MIN.into(), i64::MAX.into()) as i64
+    }
+
+    pub fn saturating_pow(self, exp: u32) -> Self {
+        self.checked_pow(exp)
+            .unwrap_or(if self.is_positive || exp & 1 == 0 {
+                Self::MAX
+            } else {
+                Self::MIN
+            })
+    }
+
+    /// Saturated sum and whether it overflowed. The sign-magnitude range
+    /// has no natural wrap point, so unlike primitives the value is clamped.
+    pub fn overflowing_add(self, other: Self) -> (Self, bool) {
+        // One checked op, so a failure is traced once
+        match self.checked_add(other) {
+            Ok(value) => (value, false),
+            Err(_) if self.is_positive => (Self::MAX, true),
+            Err(_) => (Self::MIN, true),
+        }
+    }
+
+    /// Saturated difference and whether it overflowed
+    pub fn overflowing_sub(self, other: Self) -> (Self, bool) {
+        match self.checked_sub(other) {
+            Ok(value) => (value, false),
+            Err(_) if self.is_positive => (Self::MAX, true),
+            Err(_) => (Self::MIN, true),
+        }
+    }
+
+    /// Saturated product and whether it overflowed
+    pub fn overflowing_mul(self, other: Self) -> (Self, bool) {
+        match self.checked_mul(other) {
+            Ok(value) => (value, false),
+            Err(_) if self.is_positive == other.is_positive => (Self::MAX, true),
+            Err(_) => (Self::MIN, true),
+        }
+    }
+
+    /// Nearest f64, which is lossy beyond 2^53. NaN maps to `f64::NAN`.
+    pub fn to_f64(&self) -> f64 {
+        // Parsing the decimal string rounds correctly, and reads "NaN" too
+        f64::from_str(&self.to_string()).unwrap_or(f64::NAN)
+    }
+
+    /// Bits of self as a 256-bit two's complement integer, as used by the
+    /// EVM's int256. Magnitudes beyond that range wrap modulo 2^256.
+    pub fn to_twos_complement(&self) -> Uint256 {
+        if self.is_positive {
+            self.value
+        } else {
+            wrapping_neg(self.value)
+        }
+    }
+
+    /// Reads 256-bit two's complement bits, so the result lies in
+    /// `[-2^255, 2^255 - 1]`
+    pub fn from_twos_complement(bits: Uint256) -> Self {
+        if bits.to_be_bytes()[0] & 0x80 == 0 {
+            Self::from(bits)
+        } else {
+            Self {
+                value: wrapping_neg(bits),
+                is_positive: false,
+            }
         }
     }
+
+    /// The 64 hex digits of an ABI-encoded int256 word, e.g. "0xff..ff" for
+    /// -1. Fails for NaN and values outside `[-2^255, 2^255 - 1]`.
+    pub fn to_hex_twos_complement(&self) -> CommonResult<String> {
+        let bits = self.to_twos_complement();
+        if self.is_nan() || Self::from_twos_complement(bits) != *self {
+            return Err(
+                ConversionOverflowError::new("SignedInt", "int256", self.to_string()).into(),
+            );
+        }
+        let digits: String = bits
+            .to_be_bytes()
+            .iter()
+            .map(|b| format!("{b:02x}"))
+            .collect();
+        Ok(format!("0x{digits}"))
+    }
+
+    /// Reads an int256 word of exactly 64 hex digits in either case, with or
+    /// without a "0x" prefix
+    pub fn from_hex_twos_complement(s: &str) -> CommonResult<Self> {
+        let (digits, offset) = match s.strip_prefix("0x").or_else(|| s.strip_prefix("0X")) {
+            Some(rest) => (rest, 2),
+            None => (s, 0),
+        };
+        if let Some(i) = digits.chars().position(|c| !c.is_ascii_hexdigit()) {
+            return Err(ParseSignedError::InvalidCharacter { pos: offset + i }.into());
+        }
+        if digits.len() != 64 {
+            return Err(CommonError::Generic(format!(
+                "Expected 64 hex digits for an int256 word, got {}",
+                digits.len()
+            )));
+        }
+        let bits = Self::from_str_radix(digits, 16)?.value;
+        Ok(Self::from_twos_complement(bits))
+    }
+
+    /// Parses an optional '-' and digits in `radix` from 2 to 36, without a
+    /// prefix such as "0x", e.g. "-ff" in radix 16 is -255
+    pub fn from_str_radix(s: &str, radix: u32) -> Result<Self, ParseSignedError> {
+        if !(2..=36).contains(&radix) {
+            return Err(ParseSignedError::InvalidRadix { radix });
+        }
+        if radix == 10 {
+            return Self::from_str(s);
+        }
+        // Room for the 256 digits of a binary value on top of the usual limit
+        let max = MAX_PARSE_LEN + 256;
+        if s.len() > max {
+            return Err(ParseSignedError::InputTooLong { len: s.len(), max });
+        }
+        let (is_positive, digits, offset) = match s.strip_prefix('-') {
+            Some(rest) => (false, rest, 1),
+            None => (true, s, 0),
+        };
+        if digits.is_empty() {
+            return Err(ParseSignedError::EmptyInput);
+        }
+        let base = Uint256::from(radix);
+        let mut value = Uint256::zero();
+        for (i, c) in digits.chars().enumerate() {
+            let digit = c
+                .to_digit(radix)
+                .ok_or(ParseSignedError::InvalidCharacter { pos: offset + i })?;
+            value = value
+                .checked_mul(base)
+                .and_then(|v| v.checked_add(Uint256::from(digit)))
+                .map_err(|_| ParseSignedError::MagnitudeTooLarge)?;
+        }
+        Ok(Self {
+            value,
+            is_positive: is_positive || value.is_zero(),
+        })
+    }
+
+    /// Addition wrapping like int256: both operands are taken modulo 2^256
+    /// and the result lies in `[-2^255, 2^255 - 1]`
+    pub fn wrapping_add(self, other: Self) -> Self {
+        let sum =
+            Uint512::from(self.to_twos_complement()) + Uint512::from(other.to_twos_complement());
+        Self::from_twos_complement(low_bits(sum))
+    }
+
+    /// Subtraction wrapping like int256, see [`SignedInt::wrapping_add`]
+    pub fn wrapping_sub(self, other: Self) -> Self {
+        let diff = Uint512::from(self.to_twos_complement())
+            + Uint512::from(wrapping_neg(other.to_twos_complement()));
+        Self::from_twos_complement(low_bits(diff))
+    }
+
+    /// Multiplication wrapping like int256, see [`SignedInt::wrapping_add`]
+    pub fn wrapping_mul(self, other: Self) -> Self {
+        let product = self
+            .to_twos_complement()
+            .full_mul(other.to_twos_complement());
+        Self::from_twos_complement(low_bits(product))
+    }
+}
+
+/// `value * 10^exp`, or `value / 10^-exp` truncated for negative `exp`,
+/// with `None` on overflow
+pub(crate) fn shift_pow10(value: Uint256, exp: i32) -> Option<Uint256> {
+    let scale = Uint256::from(10u8).checked_pow(exp.unsigned_abs());
+    if exp >= 0 {
+        match scale {
+            Ok(scale) => value.checked_mul(scale).ok(),
+            Err(_) => value.is_zero().then(Uint256::zero),
+        }
+    } else {
+        Some(scale.map(|scale| value / scale).unwrap_or_default())
+    }
+}
+
+/// The low 256 bits, i.e. `value mod 2^256`. Uint256's own wrapping ops
+/// are missing from cosmwasm 1.0.
+fn low_bits(value: Uint512) -> Uint256 {
+    let mut bytes = [0u8; 32];
+    bytes.copy_from_slice(&value.to_be_bytes()[32..]);
+    Uint256::from_be_bytes(bytes)
+}
+
+/// `2^256 - value mod 2^256`
+fn wrapping_neg(value: Uint256) -> Uint256 {
+    let modulus = Uint512::from(Uint256::MAX) + Uint512::from(1u8);
+    low_bits(modulus - Uint512::from(value))
+}
+
+impl Neg for SignedInt {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        // Only NaN is rejected, and it stays NaN
+        self.checked_neg().unwrap_or(self)
+    }
 }
 
-impl Rem for SignedInt {
+#[cfg(not(feature = "no-panic"))]
+impl std::ops::Rem for SignedInt {
     type Output = Self;
 
-    fn rem(self, _rhs: Self) -> Self::Output {
-        todo!()
+    /// Truncated remainder taking the sign of self, panicking on a zero
+    /// divisor like the primitive integers
+    fn rem(self, rhs: Self) -> Self::Output {
+        self.checked_rem(rhs)
+            .expect("attempt to calculate the remainder with a divisor of zero")
+    }
+}
+
+#[cfg(not(feature = "no-panic"))]
+impl num_traits::Euclid for SignedInt {
+    fn div_euclid(&self, v: &Self) -> Self {
+        self.checked_div_euclid(*v)
+            .unwrap_or_else(|err| panic!("{err}"))
+    }
+
+    fn rem_euclid(&self, v: &Self) -> Self {
+        self.checked_rem_euclid(*v)
+            .unwrap_or_else(|err| panic!("{err}"))
     }
 }
 
-impl One for SignedInt {
+#[cfg(not(feature = "no-panic"))]
+impl num_traits::One for SignedInt {
     fn one() -> Self {
         Self {
             value: Uint256::from_u128(1u128),
@@ -67,7 +724,8 @@ impl One for SignedInt {
     }
 }
 
-impl Zero for SignedInt {
+#[cfg(not(feature = "no-panic"))]
+impl num_traits::Zero for SignedInt {
     fn zero() -> Self {
         Self {
             value: Uint256::zero(),
@@ -80,37 +738,97 @@ impl Zero for SignedInt {
     }
 }
 
-impl Num for SignedInt {
-    type FromStrRadixErr = Self;
+#[cfg(not(feature = "no-panic"))]
+impl num_traits::Num for SignedInt {
+    type FromStrRadixErr = ParseSignedError;
 
-    fn from_str_radix(_str: &str, _radix: u32) -> Result<Self, Self::FromStrRadixErr> {
-        panic!("unimplemented")
+    fn from_str_radix(s: &str, radix: u32) -> Result<Self, Self::FromStrRadixErr> {
+        SignedInt::from_str_radix(s, radix)
     }
 }
 
+#[cfg(not(feature = "no-panic"))]
 impl num_traits::sign::Signed for SignedInt {
     fn abs(&self) -> Self {
-        Self {
-            value: self.value,
-            is_positive: true,
-        }
+        self.checked_abs().unwrap_or(*self)
     }
 
+    /// `self - other` when self is greater, otherwise zero. Saturates rather
+    /// than overflowing.
     fn abs_sub(&self, other: &Self) -> Self {
-        let new = *self - *other;
-        new.abs()
+        if self <= other {
+            Self::zero()
+        } else {
+            self.saturating_sub(*other)
+        }
     }
 
+    /// Zero for zero and NaN, otherwise one with the sign of the value
     fn signum(&self) -> Self {
-        todo!()
+        if self.value.is_zero() {
+            Self::zero()
+        } else if self.is_positive {
+            Self::one()
+        } else {
+            -Self::one()
+        }
     }
 
     fn is_positive(&self) -> bool {
-        todo!()
+        self.is_positive && !self.value.is_zero()
     }
 
     fn is_negative(&self) -> bool {
-        todo!()
+        !self.is_positive && !self.value.is_zero()
+    }
+}
+
+/// Integer conversions fail for NaN and values out of range
+impl num_traits::ToPrimitive for SignedInt {
+    fn to_i64(&self) -> Option<i64> {
+        i64::try_from(*self).ok()
+    }
+
+    fn to_u64(&self) -> Option<u64> {
+        self.to_u128().and_then(|v| u64::try_from(v).ok())
+    }
+
+    fn to_i128(&self) -> Option<i128> {
+        i128::try_from(*self).ok()
+    }
+
+    fn to_u128(&self) -> Option<u128> {
+        u128::try_from(*self).ok()
+    }
+
+    fn to_f64(&self) -> Option<f64> {
+        Some(SignedInt::to_f64(self))
+    }
+}
+
+/// Floats are truncated toward zero, and NaN and infinities are refused
+impl num_traits::FromPrimitive for SignedInt {
+    fn from_i64(n: i64) -> Option<Self> {
+        Some(Self::from(n))
+    }
+
+    fn from_u64(n: u64) -> Option<Self> {
+        Some(Self::from(n))
+    }
+
+    fn from_i128(n: i128) -> Option<Self> {
+        Some(Self::from(n))
+    }
+
+    fn from_u128(n: u128) -> Option<Self> {
+        Some(Self::from(n))
+    }
+
+    fn from_f64(n: f64) -> Option<Self> {
+        if !n.is_finite() {
+            return None;
+        }
+        Self::from_str(&format!("{:.0}", n.trunc())).ok()
     }
 }
 
@@ -125,29 +843,51 @@ impl ToString for SignedInt {
     }
 }
 
+/// Hex digits of the magnitude without leading zeros
+fn hex_magnitude(value: Uint256) -> String {
+    let digits: String = value
+        .to_be_bytes()
+        .iter()
+        .map(|b| format!("{b:02x}"))
+        .collect();
+    match digits.trim_start_matches('0') {
+        "" => "0".to_string(),
+        digits => digits.to_string(),
+    }
+}
+
+/// Sign and magnitude in hex, e.g. "-ff" for -255, which
+/// `from_str_radix(_, 16)` reads back. `{:#x}` puts "0x" after the sign.
+impl fmt::LowerHex for SignedInt {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.is_nan() {
+            return f.pad("NaN");
+        }
+        f.pad_integral(self.is_positive, "0x", &hex_magnitude(self.value))
+    }
+}
+
+/// As [`fmt::LowerHex`] with upper case digits
+impl fmt::UpperHex for SignedInt {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.is_nan() {
+            return f.pad("NaN");
+        }
+        let digits = hex_magnitude(self.value).to_uppercase();
+        f.pad_integral(self.is_positive, "0x", &digits)
+    }
+}
+
+#[cfg(not(feature = "no-panic"))]
 impl std::ops::Add<Self> for SignedInt {
     type Output = Self;
 
     fn add(self, rhs: Self) -> Self {
-        let value;
-        let is_positive;
-        if self.is_positive == rhs.is_positive {
-            value = self.value + rhs.value;
-            is_positive = self.is_positive;
-        } else if self.value > rhs.value {
-            value = self.value - rhs.value;
-            is_positive = self.is_positive;
-        } else if self.value < rhs.value {
-            value = rhs.value - self.value;
-            is_positive = rhs.is_positive
-        } else {
-            value = Uint256::zero();
-            is_positive = true;
-        }
-        Self { is_positive, value }
+        self.strict_add(rhs)
     }
 }
 
+#[cfg(not(feature = "no-panic"))]
 impl std::ops::Add<SignedInt> for Uint256 {
     type Output = SignedInt;
 
@@ -157,33 +897,29 @@ impl std::ops::Add<SignedInt> for Uint256 {
     }
 }
 
+#[cfg(not(feature = "no-panic"))]
 impl std::ops::Sub<Self> for SignedInt {
     type Output = Self;
 
     fn sub(self, rhs: Self) -> Self {
-        self + Self {
-            value: rhs.value,
-            is_positive: !rhs.is_positive,
-        }
+        self.strict_sub(rhs)
     }
 }
 
+#[cfg(not(feature = "no-panic"))]
 impl std::ops::Mul<Self> for SignedInt {
     type Output = Self;
 
     fn mul(self, rhs: Self) -> Self {
-        let value = self.value * rhs.value;
-        Self {
-            value,
-            is_positive: self.is_positive == rhs.is_positive || value.is_zero(),
-        }
+        self.strict_mul(rhs)
     }
 }
 
-impl std::ops::Mul<Decimal256> for SignedInt {
+#[cfg(not(feature = "no-panic"))]
+impl std::ops::Mul<cosmwasm_std::Decimal256> for SignedInt {
     type Output = Self;
 
-    fn mul(self, rhs: Decimal256) -> Self {
+    fn mul(self, rhs: cosmwasm_std::Decimal256) -> Self {
         let value = self.value * rhs;
         Self {
             value,
@@ -192,6 +928,8 @@ impl std::ops::Mul<Decimal256> for SignedInt {
     }
 }
 
+/// Unlike [`SignedInt::strict_div`], returns zero for a zero divisor, which
+/// existing callers rely on
 impl std::ops::Div<Self> for SignedInt {
     type Output = Self;
 
@@ -214,18 +952,26 @@ impl std::cmp::PartialEq for SignedInt {
     }
 }
 
+impl std::cmp::Eq for SignedInt {}
+
 impl std::cmp::PartialOrd for SignedInt {
     fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl std::cmp::Ord for SignedInt {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
         if self.is_positive == other.is_positive {
             if self.is_positive {
-                self.value.partial_cmp(&other.value)
+                self.value.cmp(&other.value)
             } else {
-                other.value.partial_cmp(&self.value)
+                other.value.cmp(&self.value)
             }
         } else if self.is_positive {
-            Some(std::cmp::Ordering::Greater)
+            std::cmp::Ordering::Greater
         } else {
-            Some(std::cmp::Ordering::Less)
+            std::cmp::Ordering::Less
         }
     }
 }
@@ -239,23 +985,108 @@ impl From<Uint256> for SignedInt {
     }
 }
 
+/// Builds from a magnitude and `is_positive` flag, so `(0, false)` is NaN
+impl From<(Uint256, bool)> for SignedInt {
+    fn from((value, is_positive): (Uint256, bool)) -> Self {
+        Self { value, is_positive }
+    }
+}
+
+impl From<u128> for SignedInt {
+    fn from(value: u128) -> Self {
+        Self::from_u128(value)
+    }
+}
+
+macro_rules! impl_from_unsigned {
+    ($($t:ty),*) => {$(
+        impl From<$t> for SignedInt {
+            fn from(value: $t) -> Self {
+                Self::from_u128(value as u128)
+            }
+        }
+    )*};
+}
+
+macro_rules! impl_from_signed {
+    ($($t:ty),*) => {$(
+        impl From<$t> for SignedInt {
+            fn from(value: $t) -> Self {
+                Self {
+                    value: Uint256::from(value.unsigned_abs() as u128),
+                    is_positive: value >= 0,
+                }
+            }
+        }
+    )*};
+}
+
+impl_from_unsigned!(u8, u16, u32, u64, usize);
+impl_from_signed!(i8, i16, i32, i64, i128, isize);
+
+/// Fails with a conversion overflow for NaN, negative values or a magnitude
+/// beyond u128
+impl TryFrom<SignedInt> for u128 {
+    type Error = CommonError;
+
+    fn try_from(value: SignedInt) -> Result<Self, Self::Error> {
+        // Zero is always positive, so this also rejects NaN
+        if !value.is_positive {
+            return Err(
+                ConversionOverflowError::new("SignedInt", "u128", value.to_string()).into(),
+            );
+        }
+        Ok(Uint128::try_from(value.value)?.u128())
+    }
+}
+
+/// Fails with a conversion overflow for NaN or a magnitude beyond i128
+impl TryFrom<SignedInt> for i128 {
+    type Error = CommonError;
+
+    fn try_from(value: SignedInt) -> Result<Self, Self::Error> {
+        let overflow = || ConversionOverflowError::new("SignedInt", "i128", value.to_string());
+        if value.is_nan() {
+            return Err(overflow().into());
+        }
+        let magnitude = Uint128::try_from(value.value)
+            .map_err(|_| overflow())?
+            .u128();
+        if !value.is_positive && magnitude == i128::MIN.unsigned_abs() {
+            return Ok(i128::MIN);
+        }
+        let magnitude = i128::try_from(magnitude).map_err(|_| overflow())?;
+        Ok(if value.is_positive {
+            magnitude
+        } else {
+            -magnitude
+        })
+    }
+}
+
+/// Fails with a conversion overflow for NaN or a value outside i64
+impl TryFrom<SignedInt> for i64 {
+    type Error = CommonError;
+
+    fn try_from(value: SignedInt) -> Result<Self, Self::Error> {
+        i128::try_from(value)
+            .ok()
+            .and_then(|v| i64::try_from(v).ok())
+            .ok_or_else(|| {
+                ConversionOverflowError::new("SignedInt", "i64", value.to_string()).into()
+            })
+    }
+}
+
 impl FromStr for SignedInt {
-    type Err = CommonError;
+    type Err = ParseSignedError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let sign;
-        let val_str;
-        let mut chars = s.chars();
-        if chars.next().unwrap() == '-' {
-            sign = false;
-            val_str = chars.as_str();
-        } else {
-            sign = true;
-            val_str = s;
-        }
+        let (sign, val_str) = ParseSignedError::split_sign(s, 0)?;
+        let value = Uint256::from_str(val_str).map_err(|_| ParseSignedError::MagnitudeTooLarge)?;
         Ok(Self {
-            value: Uint256::from_str(val_str)?,
-            is_positive: sign,
+            value,
+            is_positive: sign || value.is_zero(),
         })
     }
 }
@@ -264,7 +1095,7 @@ impl TryFrom<&str> for SignedInt {
     type Error = CommonError;
 
     fn try_from(value: &str) -> Result<Self, Self::Error> {
-        Self::from_str(value)
+        Ok(Self::from_str(value)?)
     }
 }
 
@@ -291,6 +1122,7 @@ impl Default for SignedInt {
 }
 
 #[test]
+#[cfg(not(feature = "no-panic"))]
 fn signed_int_test() {
     let big_pos = SignedInt::from_str("100").unwrap();
     let big_neg = SignedInt::from_str("-100").unwrap();
@@ -358,6 +1190,7 @@ fn signed_int_test() {
 }
 
 #[test]
+#[cfg(not(feature = "no-panic"))]
 fn test_zero_is_positive() {
     {
         let mut x = SignedInt::zero();
@@ -409,3 +1242,565 @@ fn test_zero_is_positive() {
         assert!(z.is_positive);
     }
 }
+
+#[test]
+fn test_uint256_parity() {
+    let five = SignedInt::from_u128(5);
+    let neg_three = -SignedInt::from_u128(3);
+
+    assert_eq!(
+        five.checked_add(neg_three).unwrap(),
+        SignedInt::from_u128(2)
+    );
+    assert_eq!(
+        neg_three.checked_sub(five).unwrap(),
+        -SignedInt::from_u128(8)
+    );
+    assert_eq!(
+        five.checked_mul(neg_three).unwrap(),
+        -SignedInt::from_u128(15)
+    );
+    assert_eq!(five.checked_div(neg_three).unwrap(), -SignedInt::one());
+    assert_eq!(
+        (-five).checked_rem(neg_three).unwrap(),
+        -SignedInt::from_u128(2)
+    );
+    #[cfg(not(feature = "no-panic"))]
+    {
+        assert_eq!(neg_three.pow(3), -SignedInt::from_u128(27));
+        assert_eq!(neg_three.pow(2), SignedInt::from_u128(9));
+        assert_eq!(
+            neg_three.multiply_ratio(4u128, 3u128),
+            -SignedInt::from_u128(4)
+        );
+    }
+    assert_eq!(
+        neg_three.checked_multiply_ratio(4u128, 3u128).unwrap(),
+        -SignedInt::from_u128(4)
+    );
+    assert!(neg_three.checked_multiply_ratio(4u128, 0u128).is_err());
+    assert!(SignedInt::MAX.checked_multiply_ratio(2u128, 1u128).is_err());
+
+    assert!(matches!(
+        SignedInt::MAX.checked_add(SignedInt::one()),
+        Err(CommonError::Overflow(_))
+    ));
+    assert!(matches!(
+        SignedInt::MIN.checked_sub(SignedInt::one()),
+        Err(CommonError::Overflow(_))
+    ));
+    assert!(matches!(
+        five.checked_div(SignedInt::zero()),
+        Err(CommonError::DivideByZero(_))
+    ));
+
+    assert_eq!(SignedInt::MAX.saturating_add(five), SignedInt::MAX);
+    assert_eq!(SignedInt::MIN.saturating_sub(five), SignedInt::MIN);
+    assert_eq!(SignedInt::MAX.saturating_mul(neg_three), SignedInt::MIN);
+    assert_eq!(neg_three.saturating_div(SignedInt::zero()), SignedInt::MIN);
+    assert_eq!(five.saturating_div(neg_three), -SignedInt::one());
+    assert_eq!(
+        SignedInt::zero().saturating_div(SignedInt::zero()),
+        SignedInt::zero()
+    );
+    assert_eq!(neg_three.saturating_pow(1000), SignedInt::MAX);
+    assert_eq!(neg_three.saturating_pow(1001), SignedInt::MIN);
+
+    assert_eq!(SignedInt::from(7u128), SignedInt::from_u128(7));
+    assert_eq!(SignedInt::from((Uint256::from(3u8), false)), neg_three);
+    assert_eq!(neg_three.as_tuple(), (Uint256::from(3u8), false));
+    assert!(SignedInt::from((Uint256::zero(), false)).is_nan());
+    assert_eq!(u128::try_from(five).unwrap(), 5);
+    assert!(u128::try_from(neg_three).is_err());
+    assert!(u128::try_from(SignedInt::MAX).is_err());
+}
+
+#[test]
+fn test_from_primitives() {
+    let int = |s: &str| SignedInt::from_str(s).unwrap();
+
+    assert_eq!(SignedInt::from(-5i8), int("-5"));
+    assert_eq!(SignedInt::from(i32::MIN), int("-2147483648"));
+    assert_eq!(SignedInt::from(i64::MAX), int(&i64::MAX.to_string()));
+    assert_eq!(SignedInt::from(i128::MIN), int(&i128::MIN.to_string()));
+    assert_eq!(SignedInt::from(-1isize), int("-1"));
+    assert_eq!(SignedInt::from(u8::MAX), int("255"));
+    assert_eq!(SignedInt::from(u64::MAX), int(&u64::MAX.to_string()));
+    assert_eq!(SignedInt::from(7usize), int("7"));
+    assert!(SignedInt::from(0i64).is_positive);
+}
+
+#[test]
+fn test_euclid() {
+    let int = |s: &str| SignedInt::from_str(s).unwrap();
+
+    for (a, b, q, r) in [
+        ("7", "3", "2", "1"),
+        ("-7", "3", "-3", "2"),
+        ("7", "-3", "-2", "1"),
+        ("-7", "-3", "3", "2"),
+        ("-6", "3", "-2", "0"),
+    ] {
+        assert_eq!(int(a).checked_div_euclid(int(b)).unwrap(), int(q));
+        assert_eq!(int(a).checked_rem_euclid(int(b)).unwrap(), int(r));
+        #[cfg(not(feature = "no-panic"))]
+        {
+            use num_traits::Euclid;
+
+            assert_eq!(Euclid::div_euclid(&int(a), &int(b)), int(q));
+            assert_eq!(Euclid::rem_euclid(&int(a), &int(b)), int(r));
+        }
+    }
+    assert!(int("1").checked_div_euclid(int("0")).is_err());
+    assert!(int("1").checked_rem_euclid(int("0")).is_err());
+}
+
+#[test]
+fn test_cmp_abs() {
+    let int = |s: &str| SignedInt::from_str(s).unwrap();
+
+    assert_eq!(int("-3").cmp_abs(&int("2")), Ordering::Greater);
+    assert_eq!(int("3").cmp_abs(&int("-3")), Ordering::Equal);
+    assert!(int("-3").eq_abs(&int("3")));
+    assert!(!int("-3").eq_abs(&int("2")));
+}
+
+#[test]
+#[cfg(not(feature = "no-panic"))]
+fn test_rem() {
+    let int = |s: &str| SignedInt::from_str(s).unwrap();
+
+    assert_eq!(int("7") % int("3"), int("1"));
+    assert_eq!(int("-7") % int("3"), int("-1"));
+    assert_eq!(int("7") % int("-3"), int("1"));
+    assert_eq!(int("-6") % int("3"), int("0"));
+    assert!((int("-6") % int("3")).is_positive);
+    assert!(int("7").checked_rem(int("0")).is_err());
+    assert!(std::panic::catch_unwind(|| int("7") % int("0")).is_err());
+}
+
+#[test]
+fn test_checked_neg_abs() {
+    let int = |s: &str| SignedInt::from_str(s).unwrap();
+
+    assert_eq!(int("5").checked_neg().unwrap(), int("-5"));
+    assert_eq!(int("-5").checked_abs().unwrap(), int("5"));
+    assert!(int("0").checked_neg().unwrap().is_positive);
+    assert!(SignedInt::nan().checked_neg().is_err());
+    assert!(SignedInt::nan().checked_abs().is_err());
+    assert!((-SignedInt::nan()).is_nan());
+    #[cfg(not(feature = "no-panic"))]
+    assert!(num_traits::Signed::abs(&SignedInt::nan()).is_nan());
+}
+
+#[test]
+fn test_mul_pow10() {
+    let int = |s: &str| SignedInt::from_str(s).unwrap();
+
+    assert_eq!(int("-15").mul_pow10(3).unwrap(), int("-15000"));
+    assert_eq!(int("-15999").mul_pow10(-3).unwrap(), int("-15"));
+    assert_eq!(int("-15999").div_pow10(3).unwrap(), int("-15"));
+    assert_eq!(int("-999").mul_pow10(-3).unwrap(), SignedInt::zero());
+    assert!(!int("-999").mul_pow10(-3).unwrap().is_nan());
+    assert_eq!(int("7").mul_pow10(-100).unwrap(), SignedInt::zero());
+    assert_eq!(SignedInt::zero().mul_pow10(100).unwrap(), SignedInt::zero());
+    assert_eq!(int("1").mul_pow10(77).unwrap().to_string().len(), 78);
+    assert!(int("12").mul_pow10(77).is_err());
+    assert!(int("1").mul_pow10(78).is_err());
+    assert!(int("1").div_pow10(i32::MIN).is_err());
+}
+
+#[test]
+fn test_overflowing() {
+    let one = SignedInt::from_u128(1);
+    assert_eq!(one.overflowing_add(one), (SignedInt::from_u128(2), false));
+    assert_eq!(SignedInt::MAX.overflowing_add(one), (SignedInt::MAX, true));
+    assert_eq!(SignedInt::MIN.overflowing_sub(one), (SignedInt::MIN, true));
+    assert!(!SignedInt::MIN.overflowing_sub(-one).1);
+    assert_eq!(
+        SignedInt::MAX.overflowing_mul(-SignedInt::from_u128(2)),
+        (SignedInt::MIN, true)
+    );
+}
+
+#[test]
+fn test_wrapping() {
+    let int = |s: &str| SignedInt::from_str(s).unwrap();
+    // int256 bounds
+    let max = int("57896044618658097711785492504343953926634992332820282019728792003956564819967");
+    let min = int("-57896044618658097711785492504343953926634992332820282019728792003956564819968");
+
+    assert_eq!(int("-1").to_twos_complement(), Uint256::MAX);
+    assert_eq!(SignedInt::from_twos_complement(Uint256::MAX), int("-1"));
+    assert_eq!(
+        SignedInt::from_twos_complement(min.to_twos_complement()),
+        min
+    );
+    assert_eq!(max.wrapping_add(int("1")), min);
+    assert_eq!(min.wrapping_sub(int("1")), max);
+    assert_eq!(min.wrapping_mul(int("-1")), min);
+    assert_eq!(int("-7").wrapping_mul(int("6")), int("-42"));
+    assert_eq!(int("-7").wrapping_sub(int("-7")), SignedInt::zero());
+    assert!(!int("-7").wrapping_sub(int("-7")).is_nan());
+    // Outside the int256 range, inputs wrap too
+    assert_eq!(SignedInt::MAX.wrapping_add(SignedInt::zero()), int("-1"));
+}
+
+#[test]
+fn test_to_i64_saturating() {
+    let x = SignedInt::from_str("-123456").unwrap();
+    assert_eq!(x.to_i64_saturating(0), -123456);
+    assert_eq!(x.to_i64_saturating(2), -12345600);
+    assert_eq!(x.to_i64_saturating(-3), -123);
+    assert_eq!(x.to_i64_saturating(-100), 0);
+    assert_eq!(x.to_i64_saturating(100), i64::MIN);
+    assert_eq!((-x).to_i64_saturating(100), i64::MAX);
+    // 10^78 doesn't fit a Uint256, but zero still scales to zero
+    assert_eq!(SignedInt::zero().to_i64_saturating(78), 0);
+    assert_eq!(SignedInt::nan().to_i64_saturating(i32::MAX), 0);
+    assert_eq!(SignedInt::MAX.to_i64_saturating(0), i64::MAX);
+    assert_eq!(SignedInt::MIN.to_i64_saturating(0), i64::MIN);
+    assert_eq!(
+        SignedInt::from_str("-9223372036854775808")
+            .unwrap()
+            .to_i64_saturating(0),
+        i64::MIN
+    );
+}
+
+#[test]
+fn test_comparison_laws() {
+    let values: Vec<SignedInt> = ["-100", "-1", "0", "-0", "1", "100"]
+        .iter()
+        .map(|s| SignedInt::from_str(s).unwrap())
+        .chain([SignedInt::MIN, SignedInt::MAX])
+        .collect();
+
+    assert!(!SignedInt::from_str("-0").unwrap().is_nan());
+
+    for a in &values {
+        assert_eq!(a, a);
+        assert_eq!(a.partial_cmp(a), Some(std::cmp::Ordering::Equal));
+        for b in &values {
+            assert_eq!(a == b, b == a);
+            assert_eq!(a == b, a.partial_cmp(b) == Some(std::cmp::Ordering::Equal));
+            assert_eq!(a.partial_cmp(b), b.partial_cmp(a).map(|o| o.reverse()));
+            if a <= b && b <= a {
+                assert_eq!(a, b);
+            }
+            for c in &values {
+                if a <= b && b <= c {
+                    assert!(a <= c);
+                }
+            }
+        }
+    }
+
+    assert!(SignedInt::zero().eq_uint(&Uint256::zero()));
+    assert!(SignedInt::from_u128(7).eq_uint(&Uint256::from_u128(7)));
+    assert!(!(-SignedInt::from_u128(7)).eq_uint(&Uint256::from_u128(7)));
+    assert!(!SignedInt::nan().eq_uint(&Uint256::zero()));
+}
+
+#[test]
+#[cfg(not(feature = "no-panic"))]
+fn test_snapshot_history() {
+    use cosmwasm_std::testing::MockStorage;
+    use cw_storage_plus::{SnapshotMap, Strategy};
+
+    const POSITIONS: SnapshotMap<&str, SignedInt> =
+        SnapshotMap::new("pos", "pos__check", "pos__change", Strategy::EveryBlock);
+
+    let mut storage = MockStorage::new();
+    POSITIONS
+        .save(&mut storage, "alice", &SignedInt::from_u128(100), 5)
+        .unwrap();
+    POSITIONS
+        .save(&mut storage, "alice", &-SignedInt::from_u128(40), 9)
+        .unwrap();
+
+    let old = POSITIONS
+        .may_load_at_height(&storage, "alice", 9)
+        .unwrap()
+        .unwrap();
+    let current = POSITIONS.load(&storage, "alice").unwrap();
+    assert_eq!(current.delta_since(&old), -SignedInt::from_u128(140));
+}
+
+#[cfg(all(test, not(feature = "no-panic")))]
+crate::signed_arith_laws!(arith_laws, SignedInt);
+
+#[test]
+#[cfg(not(feature = "no-panic"))]
+fn test_signed_trait() {
+    use num_traits::Signed;
+
+    let int = |s: &str| SignedInt::from_str(s).unwrap();
+
+    assert_eq!(int("-7").signum(), int("-1"));
+    assert_eq!(int("7").signum(), int("1"));
+    assert_eq!(int("0").signum(), int("0"));
+    assert!(!int("0").is_positive() && !int("0").is_negative());
+    assert!(int("3").is_positive() && int("-3").is_negative());
+
+    // The positive difference, clamped at zero and at MAX
+    assert_eq!(int("7").abs_sub(&int("-2")), int("9"));
+    assert_eq!(int("-2").abs_sub(&int("7")), int("0"));
+    assert_eq!(int("4").abs_sub(&int("4")), int("0"));
+    assert_eq!(SignedInt::MAX.abs_sub(&SignedInt::MIN), SignedInt::MAX);
+
+    // NaN has no sign either
+    let nan = SignedInt::nan();
+    assert_eq!(nan.signum(), int("0"));
+    assert!(!nan.is_positive() && !nan.is_negative());
+}
+
+#[test]
+fn test_zero_comparisons() {
+    let neg = -SignedInt::from_u128(5);
+    let zero = SignedInt::zero();
+    let pos = SignedInt::from_u128(5);
+    let nan = SignedInt::nan();
+
+    assert!(!neg.is_gt_zero() && !zero.is_gt_zero() && pos.is_gt_zero());
+    assert!(neg.is_lt_zero() && !zero.is_lt_zero() && !pos.is_lt_zero());
+    assert!(!neg.is_gte_zero() && zero.is_gte_zero() && pos.is_gte_zero());
+    assert!(neg.is_lte_zero() && zero.is_lte_zero() && !pos.is_lte_zero());
+    assert!(!nan.is_gt_zero() && !nan.is_lt_zero());
+    assert!(!nan.is_gte_zero() && !nan.is_lte_zero());
+
+    assert!(zero.is_within(&(neg..=pos)));
+    assert!(!pos.is_within(&(neg..pos)));
+    assert!(neg.is_within(&(..zero)));
+}
+
+#[test]
+fn test_try_into_signed_primitives() {
+    let int = |s: &str| SignedInt::from_str(s).unwrap();
+
+    assert_eq!(i128::try_from(int("-42")).unwrap(), -42);
+    assert_eq!(
+        i128::try_from(SignedInt::from(i128::MIN)).unwrap(),
+        i128::MIN
+    );
+    assert_eq!(
+        i128::try_from(SignedInt::from(i128::MAX)).unwrap(),
+        i128::MAX
+    );
+    assert!(i128::try_from(SignedInt::from(i128::MAX as u128 + 1)).is_err());
+    assert!(i128::try_from(-SignedInt::from(i128::MAX as u128 + 2)).is_err());
+    assert!(matches!(
+        i128::try_from(SignedInt::nan()),
+        Err(CommonError::ConversionOverflow(_))
+    ));
+
+    assert_eq!(i64::try_from(SignedInt::from(i64::MIN)).unwrap(), i64::MIN);
+    assert!(matches!(
+        i64::try_from(SignedInt::from(i64::MAX as u128 + 1)),
+        Err(CommonError::ConversionOverflow(_))
+    ));
+    assert!(i64::try_from(SignedInt::MIN).is_err());
+}
+
+#[test]
+fn test_to_f64() {
+    assert_eq!(SignedInt::from(-42i8).to_f64(), -42.0);
+    assert_eq!(SignedInt::zero().to_f64(), 0.0);
+    assert_eq!(SignedInt::from(u64::MAX).to_f64(), 18446744073709551616.0);
+    assert_eq!(SignedInt::MIN.to_f64(), -1.157920892373162e77);
+    assert!(SignedInt::nan().to_f64().is_nan());
+}
+
+#[test]
+fn test_primitive_traits() {
+    use num_traits::{FromPrimitive, ToPrimitive};
+
+    let x = SignedInt::from(-42i8);
+    assert_eq!(x.to_i64(), Some(-42));
+    assert_eq!(x.to_u64(), None);
+    assert_eq!(x.to_i32(), Some(-42));
+    assert_eq!(ToPrimitive::to_f64(&x), Some(-42.0));
+    assert_eq!(SignedInt::MAX.to_u128(), None);
+    assert_eq!(SignedInt::nan().to_i64(), None);
+    assert_eq!(SignedInt::nan().to_u64(), None);
+    assert_eq!(SignedInt::nan().to_u128(), None);
+
+    assert_eq!(SignedInt::from_i32(-7), Some(SignedInt::from(-7i8)));
+    assert_eq!(
+        <SignedInt as FromPrimitive>::from_u128(u128::MAX),
+        Some(SignedInt::from(u128::MAX))
+    );
+    assert_eq!(SignedInt::from_f64(-2.9), Some(SignedInt::from(-2i8)));
+    assert_eq!(SignedInt::from_f64(-0.5), Some(SignedInt::zero()));
+    assert_eq!(
+        SignedInt::from_f64(1e20),
+        SignedInt::from_str("100000000000000000000").ok()
+    );
+    assert_eq!(SignedInt::from_f64(f64::NAN), None);
+    assert_eq!(SignedInt::from_f64(1e100), None);
+}
+
+#[test]
+fn test_modular_arithmetic() {
+    let int = |s: &str| SignedInt::from_str(s).unwrap();
+
+    assert_eq!(int("3").mod_pow(int("4"), int("7")).unwrap(), int("4"));
+    assert_eq!(int("-3").mod_pow(int("3"), int("7")).unwrap(), int("1"));
+    assert_eq!(int("3").mod_pow(int("4"), int("-7")).unwrap(), int("4"));
+    assert_eq!(int("5").mod_pow(int("0"), int("7")).unwrap(), int("1"));
+    assert_eq!(int("5").mod_pow(int("3"), int("1")).unwrap(), int("0"));
+    // 3^-1 = 5 mod 7, so 3^-2 = 25 = 4 mod 7
+    assert_eq!(int("3").mod_pow(int("-2"), int("7")).unwrap(), int("4"));
+
+    assert_eq!(int("3").mod_inverse(int("7")).unwrap(), int("5"));
+    assert_eq!(int("-3").mod_inverse(int("7")).unwrap(), int("2"));
+    assert_eq!(int("3").mod_inverse(int("-7")).unwrap(), int("5"));
+    assert!(int("6").mod_inverse(int("9")).is_err());
+    assert!(int("0").mod_inverse(int("9")).is_err());
+    assert!(int("3").mod_inverse(int("0")).is_err());
+    assert!(SignedInt::nan().mod_pow(int("2"), int("7")).is_err());
+
+    // Fermat's little theorem with the largest 256-bit prime, 2^256 - 189
+    let p = SignedInt::MAX.checked_sub(int("188")).unwrap();
+    let a = int("-123456789");
+    let p_minus_1 = p.checked_sub(int("1")).unwrap();
+    assert_eq!(a.mod_pow(p_minus_1, p).unwrap(), int("1"));
+    let inverse = a.mod_inverse(p).unwrap();
+    let p_minus_2 = p.checked_sub(int("2")).unwrap();
+    assert_eq!(a.mod_pow(p_minus_2, p).unwrap(), inverse);
+    assert_eq!(
+        (a.mod_reduce(p).unwrap().full_mul(inverse.value) % Uint512::from(p.value)),
+        Uint512::from(1u8)
+    );
+}
+
+#[test]
+fn test_from_str_radix() {
+    let int = |s: &str| SignedInt::from_str(s).unwrap();
+    let radix = |s: &str, radix: u32| SignedInt::from_str_radix(s, radix);
+
+    assert_eq!(radix("-ff", 16).unwrap(), int("-255"));
+    assert_eq!(radix("FF", 16).unwrap(), int("255"));
+    assert_eq!(radix("-101", 2).unwrap(), int("-5"));
+    assert_eq!(radix("777", 8).unwrap(), int("511"));
+    assert_eq!(radix("-42", 10).unwrap(), int("-42"));
+    assert_eq!(radix("zz", 36).unwrap(), int("1295"));
+    assert!(!radix("-0", 16).unwrap().is_nan());
+    assert_eq!(radix(&"f".repeat(64), 16).unwrap().value, Uint256::MAX);
+    assert_eq!(
+        radix(&format!("-{}", "1".repeat(256)), 2).unwrap(),
+        SignedInt::MIN
+    );
+
+    assert_eq!(
+        radix(&"1".repeat(257), 2),
+        Err(ParseSignedError::MagnitudeTooLarge)
+    );
+    assert_eq!(
+        radix(&"f".repeat(65), 16),
+        Err(ParseSignedError::MagnitudeTooLarge)
+    );
+    assert_eq!(
+        radix("12", 2),
+        Err(ParseSignedError::InvalidCharacter { pos: 1 })
+    );
+    assert_eq!(
+        radix("-0x1f", 16),
+        Err(ParseSignedError::InvalidCharacter { pos: 2 })
+    );
+    assert_eq!(radix("-", 16), Err(ParseSignedError::EmptyInput));
+    assert_eq!(
+        radix("1", 1),
+        Err(ParseSignedError::InvalidRadix { radix: 1 })
+    );
+    assert_eq!(
+        radix("1", 37),
+        Err(ParseSignedError::InvalidRadix { radix: 37 })
+    );
+    assert!(matches!(
+        radix(&"0".repeat(1000), 16),
+        Err(ParseSignedError::InputTooLong { .. })
+    ));
+}
+
+#[test]
+fn test_hex() {
+    let int = |s: &str| SignedInt::from_str(s).unwrap();
+    let max = SignedInt::from_twos_complement(Uint256::MAX >> 1);
+    let min = (-max).checked_sub(int("1")).unwrap();
+
+    assert_eq!(
+        int("-1").to_hex_twos_complement().unwrap(),
+        format!("0x{}", "f".repeat(64))
+    );
+    assert_eq!(
+        int("255").to_hex_twos_complement().unwrap(),
+        format!("0x{}ff", "0".repeat(62))
+    );
+    assert_eq!(
+        min.to_hex_twos_complement().unwrap(),
+        format!("0x8{}", "0".repeat(63))
+    );
+    for x in [int("0"), int("-1"), int("-256"), int("42"), min, max] {
+        let hex = x.to_hex_twos_complement().unwrap();
+        assert_eq!(SignedInt::from_hex_twos_complement(&hex).unwrap(), x);
+        let bare = hex.trim_start_matches("0x").to_uppercase();
+        assert_eq!(SignedInt::from_hex_twos_complement(&bare).unwrap(), x);
+    }
+    assert!(SignedInt::MAX.to_hex_twos_complement().is_err());
+    let beyond = max.checked_add(int("1")).unwrap();
+    assert!(beyond.to_hex_twos_complement().is_err());
+    assert!(SignedInt::nan().to_hex_twos_complement().is_err());
+
+    assert!(SignedInt::from_hex_twos_complement("0xff").is_err());
+    assert!(SignedInt::from_hex_twos_complement(&"0".repeat(65)).is_err());
+    assert_eq!(
+        SignedInt::from_hex_twos_complement(&format!("0x-{}", "1".repeat(63))),
+        Err(ParseSignedError::InvalidCharacter { pos: 2 }.into())
+    );
+
+    assert_eq!(format!("{:x}", int("-255")), "-ff");
+    assert_eq!(format!("{:X}", int("255")), "FF");
+    assert_eq!(format!("{:#x}", int("-255")), "-0xff");
+    assert_eq!(format!("{:08x}", int("-255")), "-00000ff");
+    assert_eq!(format!("{:x}", int("0")), "0");
+    assert_eq!(format!("{:x}", SignedInt::nan()), "NaN");
+    assert_eq!(format!("{:x}", SignedInt::MAX), "f".repeat(64));
+    let hex = format!("{:x}", int("-123456789"));
+    assert_eq!(
+        SignedInt::from_str_radix(&hex, 16).unwrap(),
+        int("-123456789")
+    );
+}
+
+#[test]
+#[cfg(feature = "no-panic")]
+fn test_no_panic() {
+    use crate::signed_decimal::{IntScale, SignedDecimal};
+
+    // Only the fallible API is available, and every failure is an error
+    let int = |s: &str| SignedInt::from_str(s).unwrap();
+    let (a, b) = (int("-7"), int("2"));
+    assert_eq!(a.checked_add(b).unwrap(), int("-5"));
+    assert_eq!(a.checked_sub(b).unwrap(), int("-9"));
+    assert_eq!(a.checked_mul(b).unwrap(), int("-14"));
+    assert_eq!(a.checked_div(b).unwrap(), int("-3"));
+    assert_eq!(a.checked_rem(b).unwrap(), int("-1"));
+    assert_eq!(a.checked_rem_euclid(b).unwrap(), int("1"));
+    assert_eq!(a.checked_pow(3).unwrap(), int("-343"));
+    assert_eq!(a.checked_multiply_ratio(3u128, 2u128).unwrap(), int("-10"));
+    assert_eq!(SignedInt::from_str_radix("-ff", 16).unwrap(), int("-255"));
+
+    assert!(SignedInt::MAX.checked_add(b).is_err());
+    assert!(SignedInt::MIN.checked_sub(b).is_err());
+    assert!(SignedInt::MAX.checked_mul(b).is_err());
+    assert!(a.checked_div(SignedInt::zero()).is_err());
+    assert!(a.checked_rem(SignedInt::zero()).is_err());
+    assert!(TryInto::<Uint256>::try_into(a).is_err());
+
+    let dec = SignedDecimal::from_str("-1.5").unwrap();
+    assert!(dec.try_value().is_err());
+    assert!(dec
+        .checked_add_int(SignedInt::MAX, IntScale::Whole)
+        .is_err());
+}
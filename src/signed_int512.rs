@@ -0,0 +1,218 @@
+use std::{cmp::Ordering, convert::TryFrom, fmt, ops::Neg};
+
+use cosmwasm_std::{ConversionOverflowError, OverflowError, OverflowOperation, Uint256, Uint512};
+
+use crate::{
+    error::{CommonError, CommonResult},
+    signed_int::SignedInt,
+};
+
+/// Uint512 with a sign, for accumulating SignedInts without overflow.
+///
+/// Each SignedInt magnitude is below 2^256, so summing fewer than 2^255 of
+/// them from zero stays far below 2^512. Adding or subtracting a SignedInt
+/// and the `Sum` impls rely on this and never fail in practice, so only
+/// narrowing the total back into a SignedInt is checked. Those impls still
+/// panic past 512 bits, so the `no-panic` feature leaves them out in favour
+/// of `checked_add` and `checked_sub`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct SignedInt512 {
+    pub value: Uint512,
+    pub is_positive: bool,
+}
+
+impl SignedInt512 {
+    pub const fn zero() -> Self {
+        Self {
+            value: Uint512::zero(),
+            is_positive: true,
+        }
+    }
+
+    pub fn checked_add(self, other: Self) -> CommonResult<Self> {
+        add(self, other)
+            .ok_or_else(|| OverflowError::new(OverflowOperation::Add, self, other).into())
+    }
+
+    pub fn checked_sub(self, other: Self) -> CommonResult<Self> {
+        add(self, -other)
+            .ok_or_else(|| OverflowError::new(OverflowOperation::Sub, self, other).into())
+    }
+}
+
+/// Sign-magnitude sum, or `None` when the magnitude passes 512 bits
+fn add(a: SignedInt512, b: SignedInt512) -> Option<SignedInt512> {
+    if a.is_positive != b.is_positive {
+        // Magnitudes with opposite signs shrink, so this can't overflow
+        return Some(match a.value.cmp(&b.value) {
+            Ordering::Greater => SignedInt512 {
+                value: a.value - b.value,
+                is_positive: a.is_positive,
+            },
+            Ordering::Less => SignedInt512 {
+                value: b.value - a.value,
+                is_positive: b.is_positive,
+            },
+            Ordering::Equal => SignedInt512::zero(),
+        });
+    }
+    let value = a.value.checked_add(b.value).ok()?;
+    Some(SignedInt512 {
+        value,
+        is_positive: a.is_positive,
+    })
+}
+
+impl Default for SignedInt512 {
+    fn default() -> Self {
+        Self::zero()
+    }
+}
+
+impl Neg for SignedInt512 {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self {
+            value: self.value,
+            is_positive: !self.is_positive || self.value.is_zero(),
+        }
+    }
+}
+
+/// Exact. NaN widens to zero, as it adds in [`SignedInt::checked_add`].
+impl From<SignedInt> for SignedInt512 {
+    fn from(value: SignedInt) -> Self {
+        Self {
+            value: Uint512::from(value.value),
+            is_positive: value.is_positive || value.value.is_zero(),
+        }
+    }
+}
+
+/// Fails with a conversion overflow when the magnitude exceeds 256 bits
+impl TryFrom<SignedInt512> for SignedInt {
+    type Error = CommonError;
+
+    fn try_from(value: SignedInt512) -> CommonResult<Self> {
+        let magnitude = Uint256::try_from(value.value).map_err(|_| {
+            ConversionOverflowError::new("SignedInt512", "SignedInt", value.to_string())
+        })?;
+        Ok(SignedInt {
+            value: magnitude,
+            is_positive: value.is_positive,
+        })
+    }
+}
+
+#[cfg(not(feature = "no-panic"))]
+impl std::ops::Add<SignedInt> for SignedInt512 {
+    type Output = Self;
+
+    fn add(self, rhs: SignedInt) -> Self {
+        add(self, rhs.into()).expect("attempt to add with overflow")
+    }
+}
+
+#[cfg(not(feature = "no-panic"))]
+impl std::ops::Sub<SignedInt> for SignedInt512 {
+    type Output = Self;
+
+    fn sub(self, rhs: SignedInt) -> Self {
+        add(self, -SignedInt512::from(rhs)).expect("attempt to subtract with overflow")
+    }
+}
+
+#[cfg(not(feature = "no-panic"))]
+impl std::ops::AddAssign<SignedInt> for SignedInt512 {
+    fn add_assign(&mut self, rhs: SignedInt) {
+        *self = *self + rhs;
+    }
+}
+
+#[cfg(not(feature = "no-panic"))]
+impl std::ops::SubAssign<SignedInt> for SignedInt512 {
+    fn sub_assign(&mut self, rhs: SignedInt) {
+        *self = *self - rhs;
+    }
+}
+
+#[cfg(not(feature = "no-panic"))]
+impl std::iter::Sum<SignedInt> for SignedInt512 {
+    fn sum<I: Iterator<Item = SignedInt>>(iter: I) -> Self {
+        iter.fold(Self::zero(), |mut total, value| {
+            total += value;
+            total
+        })
+    }
+}
+
+#[cfg(not(feature = "no-panic"))]
+impl<'a> std::iter::Sum<&'a SignedInt> for SignedInt512 {
+    fn sum<I: Iterator<Item = &'a SignedInt>>(iter: I) -> Self {
+        iter.copied().sum()
+    }
+}
+
+impl fmt::Display for SignedInt512 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if !self.is_positive {
+            f.write_str("-")?;
+        }
+        write!(f, "{}", self.value)
+    }
+}
+
+#[test]
+fn test_signed_int512() {
+    use std::str::FromStr;
+
+    let int = |s: &str| SignedInt::from_str(s).unwrap();
+
+    let wide = SignedInt::MAX.wide_add(SignedInt::MAX);
+    assert_eq!(wide.value, Uint512::from(Uint256::MAX) * Uint512::from(2u8));
+    assert!(SignedInt::try_from(wide).is_err());
+    assert_eq!(
+        SignedInt::try_from(wide.checked_sub(SignedInt::MAX.into()).unwrap()).unwrap(),
+        SignedInt::MAX
+    );
+    assert_eq!(
+        SignedInt::MIN.wide_sub(SignedInt::MAX).to_string(),
+        format!("-{}", wide.value)
+    );
+    assert_eq!(int("-5").wide_sub(int("-5")), SignedInt512::zero());
+    assert_eq!(SignedInt::nan().wide_add(int("-3")), int("-3").into());
+
+    let floor = SignedInt512 {
+        value: Uint512::MAX,
+        is_positive: false,
+    };
+    assert!(floor.checked_add(int("-1").into()).is_err());
+    assert_eq!(floor.checked_sub(floor).unwrap(), SignedInt512::zero());
+}
+
+#[test]
+#[cfg(not(feature = "no-panic"))]
+fn test_signed_int512_sum() {
+    use std::str::FromStr;
+
+    let int = |s: &str| SignedInt::from_str(s).unwrap();
+
+    // Totals can pass the SignedInt range along the way as long as they end
+    // inside it
+    let pnl = [SignedInt::MAX, SignedInt::MAX, int("-7"), SignedInt::MIN];
+    let total: SignedInt512 = pnl.iter().sum();
+    assert!(matches!(
+        SignedInt::try_from(total - SignedInt::MIN),
+        Err(CommonError::ConversionOverflow(_))
+    ));
+    assert_eq!(
+        SignedInt::try_from(total).unwrap(),
+        SignedInt::MAX.checked_sub(int("7")).unwrap()
+    );
+
+    let mut total = SignedInt512::zero();
+    total -= int("2");
+    total += int("1");
+    assert_eq!(SignedInt::try_from(total).unwrap(), int("-1"));
+}
@@ -0,0 +1,152 @@
+//! cw-storage-plus keys ordered by value, so a `Map` over them ranges in
+//! numeric order.
+//!
+//! Keys in cw-storage-plus 0.13 borrow their bytes from the key itself, and
+//! the sortable encoding of a SignedInt or SignedDecimal is computed rather
+//! than stored, so maps are keyed by [`SortableKey`] holding the encoded
+//! bytes. Ranges deserialize straight back into the value type.
+
+use std::marker::PhantomData;
+
+use cosmwasm_std::{StdError, StdResult};
+use cw_storage_plus::{Key, KeyDeserialize, Prefixer, PrimaryKey};
+
+use crate::{
+    codec::{CompactCodec, COMPACT_LEN},
+    signed_decimal::SignedDecimal,
+    signed_int::SignedInt,
+};
+
+/// Map key holding [`CompactCodec::to_sortable_bytes`] of a value, e.g.
+/// `Map<SortableKey<SignedDecimal>, Order>` for an order book by price
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub struct SortableKey<T> {
+    bytes: [u8; COMPACT_LEN],
+    value: PhantomData<T>,
+}
+
+impl<T: CompactCodec> SortableKey<T> {
+    pub fn new(value: &T) -> Self {
+        Self {
+            bytes: value.to_sortable_bytes(),
+            value: PhantomData,
+        }
+    }
+
+    /// The value this key was made from
+    pub fn value(&self) -> T {
+        T::from_sortable_bytes(&self.bytes).expect("key holds a valid encoding")
+    }
+}
+
+impl From<SignedInt> for SortableKey<SignedInt> {
+    fn from(value: SignedInt) -> Self {
+        Self::new(&value)
+    }
+}
+
+impl From<SignedDecimal> for SortableKey<SignedDecimal> {
+    fn from(value: SignedDecimal) -> Self {
+        Self::new(&value)
+    }
+}
+
+impl<'a, T: CompactCodec + Clone> PrimaryKey<'a> for SortableKey<T> {
+    type Prefix = ();
+    type SubPrefix = ();
+    type Suffix = Self;
+    type SuperSuffix = Self;
+
+    fn key(&self) -> Vec<Key<'_>> {
+        vec![Key::Ref(&self.bytes)]
+    }
+}
+
+impl<'a, T> Prefixer<'a> for SortableKey<T> {
+    fn prefix(&self) -> Vec<Key<'_>> {
+        vec![Key::Ref(&self.bytes)]
+    }
+}
+
+impl<T: CompactCodec> KeyDeserialize for SortableKey<T> {
+    type Output = T;
+
+    fn from_vec(value: Vec<u8>) -> StdResult<T> {
+        from_key_bytes(&value)
+    }
+}
+
+impl KeyDeserialize for SignedInt {
+    type Output = Self;
+
+    fn from_vec(value: Vec<u8>) -> StdResult<Self> {
+        from_key_bytes(&value)
+    }
+}
+
+impl KeyDeserialize for SignedDecimal {
+    type Output = Self;
+
+    fn from_vec(value: Vec<u8>) -> StdResult<Self> {
+        from_key_bytes(&value)
+    }
+}
+
+fn from_key_bytes<T: CompactCodec>(bytes: &[u8]) -> StdResult<T> {
+    T::from_sortable_bytes(bytes).map_err(|e| StdError::parse_err(std::any::type_name::<T>(), e))
+}
+
+#[test]
+fn test_sortable_key() {
+    use cosmwasm_std::{testing::MockStorage, Order};
+    use cw_storage_plus::{Bound, Map};
+    use std::str::FromStr;
+
+    let dec = |s: &str| SignedDecimal::from_str(s).unwrap();
+    let prices = ["-12.5", "-0.000000000000000001", "0", "0.25", "3"].map(dec);
+
+    let mut storage = MockStorage::new();
+    let book: Map<SortableKey<SignedDecimal>, u32> = Map::new("book");
+    for (i, price) in prices.iter().enumerate().rev() {
+        book.save(&mut storage, (*price).into(), &(i as u32))
+            .unwrap();
+    }
+    let scanned: Vec<_> = book
+        .range(&storage, None, None, Order::Ascending)
+        .map(|item| item.unwrap().0)
+        .collect();
+    assert_eq!(scanned, prices);
+
+    let below_zero: Vec<_> = book
+        .keys(
+            &storage,
+            None,
+            Some(Bound::exclusive(SortableKey::from(dec("0")))),
+            Order::Descending,
+        )
+        .map(|key| key.unwrap())
+        .collect();
+    assert_eq!(below_zero, [prices[1], prices[0]]);
+
+    // As the leading part of a composite key
+    let by_account: Map<(SortableKey<SignedInt>, &str), bool> = Map::new("accounts");
+    let balance = SignedInt::from_str("-7").unwrap();
+    by_account
+        .save(&mut storage, (balance.into(), "alice"), &true)
+        .unwrap();
+    let (key, _) = by_account
+        .range(&storage, None, None, Order::Ascending)
+        .next()
+        .unwrap()
+        .unwrap();
+    assert_eq!(key, (balance, "alice".to_string()));
+    let accounts: Vec<_> = by_account
+        .prefix(balance.into())
+        .keys(&storage, None, None, Order::Ascending)
+        .map(|key| key.unwrap())
+        .collect();
+    assert_eq!(accounts, ["alice"]);
+
+    assert_eq!(SortableKey::from(SignedInt::MIN).value(), SignedInt::MIN);
+    assert!(SignedDecimal::from_slice(&[2u8; COMPACT_LEN]).is_err());
+}
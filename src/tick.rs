@@ -0,0 +1,98 @@
+use cosmwasm_std::Decimal256;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    error::CommonResult,
+    signed_decimal::{RoundingMode, SignedDecimal},
+    signed_int::SignedInt,
+};
+
+/// Price level on a grid of `tick_size` steps around a `base` price, where
+/// tick `k` is the price `base + k * tick_size`. Ticks below the base are
+/// negative, so levels on both sides of it share one ordered index.
+#[derive(
+    Serialize, Deserialize, Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, JsonSchema,
+)]
+pub struct TickIndex(pub SignedInt);
+
+impl TickIndex {
+    /// Price of this tick
+    pub fn to_price(
+        &self,
+        tick_size: Decimal256,
+        base: SignedDecimal,
+    ) -> CommonResult<SignedDecimal> {
+        let offset = SignedDecimal::from(Decimal256::new(
+            self.0.value.checked_mul(tick_size.atomics())?,
+        ));
+        if self.0.is_positive {
+            base.checked_add(offset)
+        } else {
+            base.checked_sub(offset)
+        }
+    }
+
+    /// Tick of `price`, rounding prices between two ticks with `mode`.
+    ///
+    /// Rounding follows the price rather than the tick's magnitude, so with
+    /// `Floor` a price just under the base lands on tick -1, not 0.
+    pub fn from_price(
+        price: SignedDecimal,
+        tick_size: Decimal256,
+        base: SignedDecimal,
+        mode: RoundingMode,
+    ) -> CommonResult<Self> {
+        let offset = price.checked_sub(base)?;
+        let is_positive = offset.is_gte_zero();
+        let value = mode.div(
+            offset.abs_value().atomics().into(),
+            tick_size.atomics().into(),
+            is_positive,
+        )?;
+        Ok(Self(SignedInt {
+            value,
+            is_positive: is_positive || value.is_zero(),
+        }))
+    }
+}
+
+#[test]
+fn test_tick_index() {
+    use std::str::FromStr;
+
+    let dec = |s: &str| SignedDecimal::from_str(s).unwrap();
+    let tick = |s: &str| TickIndex(SignedInt::from_str(s).unwrap());
+    let tick_size = Decimal256::from_str("0.01").unwrap();
+    let base = dec("100");
+
+    assert_eq!(tick("0").to_price(tick_size, base).unwrap(), dec("100"));
+    assert_eq!(tick("25").to_price(tick_size, base).unwrap(), dec("100.25"));
+    assert_eq!(tick("-3").to_price(tick_size, base).unwrap(), dec("99.97"));
+    assert!(tick("-3") < tick("-2") && tick("-2") < tick("0"));
+
+    let from_price = |s: &str, mode| TickIndex::from_price(dec(s), tick_size, base, mode).unwrap();
+    assert_eq!(from_price("99.97", RoundingMode::Floor), tick("-3"));
+    assert_eq!(from_price("99.975", RoundingMode::Floor), tick("-3"));
+    assert_eq!(from_price("99.975", RoundingMode::Ceil), tick("-2"));
+    assert_eq!(from_price("99.975", RoundingMode::TowardZero), tick("-2"));
+    assert_eq!(from_price("99.975", RoundingMode::AwayFromZero), tick("-3"));
+    assert_eq!(from_price("99.975", RoundingMode::HalfUp), tick("-3"));
+    assert_eq!(from_price("99.999", RoundingMode::Floor), tick("-1"));
+    assert_eq!(from_price("99.999", RoundingMode::Ceil), tick("0"));
+    assert!(from_price("99.999", RoundingMode::Ceil).0.is_positive);
+    assert_eq!(from_price("100.004", RoundingMode::HalfUp), tick("0"));
+    assert_eq!(from_price("100.005", RoundingMode::HalfUp), tick("1"));
+
+    for s in ["-40", "-1", "0", "7"] {
+        let price = tick(s).to_price(tick_size, base).unwrap();
+        assert_eq!(from_price(&price.to_string(), RoundingMode::Floor), tick(s));
+    }
+    assert!(TickIndex::from_price(base, Decimal256::zero(), base, RoundingMode::Floor).is_err());
+    // Prices and bases near the ends of the range overflow instead of panicking
+    assert!(tick("1").to_price(tick_size, SignedDecimal::MAX).is_err());
+    assert!(tick("-1").to_price(tick_size, SignedDecimal::MIN).is_err());
+    assert!(
+        TickIndex::from_price(SignedDecimal::MIN, tick_size, base, RoundingMode::Floor).is_err()
+    );
+}
@@ -0,0 +1,353 @@
+use cosmwasm_std::{Decimal256, Uint128, Uint256};
+
+use crate::signed_decimal::SignedDecimal;
+
+/// π rounded to 18 decimal places
+pub const PI: Decimal256 = Decimal256::raw(3_141_592_653_589_793_238);
+
+/// π / 2 rounded to 18 decimal places
+pub const FRAC_PI_2: Decimal256 = Decimal256::raw(1_570_796_326_794_896_619);
+
+/// 2π rounded to 18 decimal places
+pub const TAU: Decimal256 = Decimal256::raw(6_283_185_307_179_586_477);
+
+/// Euler's number rounded to 18 decimal places
+pub const E: Decimal256 = Decimal256::raw(2_718_281_828_459_045_235);
+
+/// Beyond this magnitude tanh is within 1e-18 of ±1
+const TANH_SATURATION: Decimal256 = Decimal256::raw(22_000_000_000_000_000_000);
+
+/// Sine of `x` radians.
+///
+/// The argument is reduced into [0, π/2] and evaluated with a Taylor series,
+/// which is accurate to a few atomics. Since the reduction uses TAU rounded to
+/// 18 decimals, the absolute error grows by roughly 1e-18 per full turn of `x`.
+pub fn sin(x: SignedDecimal) -> SignedDecimal {
+    let mut r = reduce(x);
+    // sin is odd, so work with |x| and flip the result for negative inputs
+    let mut negate = x.is_lt_zero();
+    if r > PI {
+        r -= PI;
+        negate = !negate;
+    }
+    if r > FRAC_PI_2 {
+        r = PI - r;
+    }
+    let value = alternating_series(r, r, 1);
+    if negate {
+        -value
+    } else {
+        value
+    }
+}
+
+/// Cosine of `x` radians, with the same accuracy as [`sin`].
+pub fn cos(x: SignedDecimal) -> SignedDecimal {
+    // cos is even, so the sign of x doesn't matter
+    let mut r = reduce(x);
+    if r > PI {
+        r = TAU - r;
+    }
+    let mut negate = false;
+    if r > FRAC_PI_2 {
+        r = PI - r;
+        negate = true;
+    }
+    let value = alternating_series(r, Decimal256::one(), 0);
+    if negate {
+        -value
+    } else {
+        value
+    }
+}
+
+/// Arctangent of `x`, in radians within [-π/2, π/2].
+///
+/// The argument is folded into [0, 1] and halved twice before summing the
+/// Taylor series, keeping the error within a few atomics.
+pub fn atan(x: SignedDecimal) -> SignedDecimal {
+    let value = SignedDecimal::from(atan_abs(x.abs_value()));
+    if x.is_lt_zero() {
+        -value
+    } else {
+        value
+    }
+}
+
+/// Angle of the point (`x`, `y`) from the positive x axis, in radians within
+/// (-π, π]. Returns zero when both coordinates are zero.
+pub fn atan2(y: SignedDecimal, x: SignedDecimal) -> SignedDecimal {
+    let y_abs = y.abs_value();
+    let x_abs = x.abs_value();
+    if x_abs.is_zero() && y_abs.is_zero() {
+        return SignedDecimal::zero();
+    }
+    // Always divide the smaller magnitude by the larger one so the quotient
+    // can't overflow, then unfold the angle into the right octant
+    let mut angle = if y_abs > x_abs {
+        FRAC_PI_2 - atan_abs(x_abs / y_abs)
+    } else {
+        atan_abs(y_abs / x_abs)
+    };
+    if x.is_lt_zero() {
+        angle = PI - angle;
+    }
+    let angle = SignedDecimal::from(angle);
+    if y.is_lt_zero() {
+        -angle
+    } else {
+        angle
+    }
+}
+
+/// Signed angle rotating vector `a` onto vector `b`, in radians within
+/// (-π, π]. Positive angles are counter-clockwise.
+///
+/// Each vector is first divided by its largest component, which keeps its
+/// direction, so components of any size (such as pool reserves) work. A
+/// component far smaller than the other one loses digits there, which moves
+/// the angle by at most a few atomics.
+pub fn angle_between(
+    a: (SignedDecimal, SignedDecimal),
+    b: (SignedDecimal, SignedDecimal),
+) -> SignedDecimal {
+    let (a, b) = (unit_scale(a), unit_scale(b));
+    // Components are now within [-1, 1], so these never saturate
+    let cross =
+        a.0.saturating_mul(b.1)
+            .saturating_sub(a.1.saturating_mul(b.0));
+    let dot =
+        a.0.saturating_mul(b.0)
+            .saturating_add(a.1.saturating_mul(b.1));
+    atan2(cross, dot)
+}
+
+/// `v` divided by its largest component magnitude, or `v` itself if it is
+/// the zero vector
+fn unit_scale(v: (SignedDecimal, SignedDecimal)) -> (SignedDecimal, SignedDecimal) {
+    let max = v.0.abs_value().max(v.1.abs_value());
+    if max.is_zero() {
+        return v;
+    }
+    // |x| <= max, so the quotient is at most one
+    let scale = |x: SignedDecimal| SignedDecimal::from((x.abs_value() / max, !x.is_lt_zero()));
+    (scale(v.0), scale(v.1))
+}
+
+/// Hyperbolic tangent of `x`, within [-1, 1].
+///
+/// Evaluated as `(e^2x - 1) / (e^2x + 1)` with the exponential split into an
+/// integer power of [`E`] and a Taylor series over the fractional part. The
+/// result is within 1e-17 of the exact value, and saturates to exactly ±1 once
+/// |x| exceeds 22.
+pub fn tanh(x: SignedDecimal) -> SignedDecimal {
+    let a = x.abs_value();
+    let value = if a > TANH_SATURATION {
+        Decimal256::one()
+    } else {
+        let exp = exp_abs(a + a);
+        (exp - Decimal256::one()) / (exp + Decimal256::one())
+    };
+    let value = SignedDecimal::from(value);
+    if x.is_lt_zero() {
+        -value
+    } else {
+        value
+    }
+}
+
+/// e raised to a non-negative `y`, which must be small enough not to overflow
+fn exp_abs(y: Decimal256) -> Decimal256 {
+    let whole = y.atomics() / Decimal256::one().atomics();
+    let frac = Decimal256::new(y.atomics() % Decimal256::one().atomics());
+    let mut term = Decimal256::one();
+    let mut sum = Decimal256::one();
+    let mut k = 1u128;
+    while !term.is_zero() {
+        term = term * frac / Uint256::from_u128(k);
+        sum += term;
+        k += 1;
+    }
+    let whole: u32 = Uint128::try_from(whole).unwrap().u128() as u32;
+    E.checked_pow(whole).unwrap() * sum
+}
+
+/// Arctangent of a non-negative `a`, within [0, π/2]
+fn atan_abs(a: Decimal256) -> Decimal256 {
+    if a > Decimal256::one() {
+        return FRAC_PI_2 - atan_abs(Decimal256::one() / a);
+    }
+    // atan(a) = 2 * atan(a / (1 + sqrt(1 + a^2))), applied twice brings a
+    // below tan(π/16) so the series needs only a dozen terms
+    let mut r = a;
+    for _ in 0..2 {
+        r = r / (Decimal256::one() + (Decimal256::one() + r * r).sqrt());
+    }
+    let r2 = r * r;
+    let mut power = r;
+    let mut sum = SignedDecimal::from(r);
+    let mut k = 1u128;
+    loop {
+        power *= r2;
+        let term = SignedDecimal::from(power / Uint256::from_u128(2 * k + 1));
+        if term.is_zero() {
+            break;
+        }
+        // The partial sums stay below 1, so these never saturate
+        sum = if k & 1 == 1 {
+            sum.saturating_sub(term)
+        } else {
+            sum.saturating_add(term)
+        };
+        k += 1;
+    }
+    Decimal256::new(sum.abs_value().atomics() * Uint256::from_u128(4))
+}
+
+/// Reduces |x| into [0, 2π)
+fn reduce(x: SignedDecimal) -> Decimal256 {
+    Decimal256::new(x.abs_value().atomics() % TAU.atomics())
+}
+
+/// Sums `first - first * r^2 / ((k + 1)(k + 2)) + ...` until the terms vanish
+/// at 18 decimal places. Only converges quickly for small `r`.
+fn alternating_series(r: Decimal256, first: Decimal256, mut k: u128) -> SignedDecimal {
+    let r2 = r * r;
+    let mut term = first;
+    let mut sum = SignedDecimal::from(first);
+    let mut subtract = true;
+    while !term.is_zero() {
+        term = term * r2 / Uint256::from_u128((k + 1) * (k + 2));
+        let signed_term = SignedDecimal::from(term);
+        // The partial sums stay close to `first`, so these never saturate
+        sum = if subtract {
+            sum.saturating_sub(signed_term)
+        } else {
+            sum.saturating_add(signed_term)
+        };
+        subtract = !subtract;
+        k += 2;
+    }
+    sum
+}
+
+/// Asserts `actual` is within 10^-17 of `expected`
+#[cfg(test)]
+fn assert_close(actual: SignedDecimal, expected: &str) {
+    let expected = dec(expected);
+    let tolerance = SignedDecimal::from(Decimal256::raw(10));
+    assert!(
+        actual.checked_sub(expected).unwrap().checked_abs().unwrap() <= tolerance,
+        "{} != {}",
+        actual.to_string(),
+        expected.to_string()
+    );
+}
+
+#[cfg(test)]
+fn dec(s: &str) -> SignedDecimal {
+    use std::str::FromStr;
+
+    SignedDecimal::from_str(s).unwrap()
+}
+
+#[test]
+fn test_sin_cos() {
+    let pi = SignedDecimal::from(PI);
+    let half_pi = SignedDecimal::from(FRAC_PI_2);
+    let six = dec("6");
+    let three = dec("3");
+
+    assert_close(sin(dec("0")), "0");
+    assert_close(sin(half_pi), "1");
+    assert_close(sin(-half_pi), "-1");
+    assert_close(sin(pi.checked_div(six).unwrap()), "0.5");
+    assert_close(sin(-pi.checked_div(six).unwrap()), "-0.5");
+    assert_close(sin(pi), "0");
+    assert_close(sin(dec("1")), "0.841470984807896506");
+    assert_close(sin(dec("-4")), "0.756802495307928251");
+    assert_close(sin(dec("100")), "-0.506365641109758793");
+
+    assert_close(cos(dec("0")), "1");
+    assert_close(cos(pi), "-1");
+    assert_close(cos(-pi), "-1");
+    assert_close(cos(pi.checked_div(three).unwrap()), "0.5");
+    assert_close(cos(half_pi), "0");
+    assert_close(cos(dec("-2")), "-0.416146836547142386");
+    assert_close(cos(dec("5")), "0.283662185463226264");
+}
+
+#[test]
+fn test_atan() {
+    let pi = SignedDecimal::from(PI);
+    let half_pi = SignedDecimal::from(FRAC_PI_2);
+    let quarter_pi = pi.checked_div(dec("4")).unwrap();
+
+    assert_close(atan(dec("0")), "0");
+    assert_close(atan(dec("1")), "0.785398163397448309");
+    assert_close(atan(dec("-1")), "-0.785398163397448309");
+    assert_close(atan(dec("0.5")), "0.463647609000806116");
+    assert_close(atan(dec("-3")), "-1.249045772398254428");
+    assert_close(atan(dec("1000000")), "1.570795326794896619");
+
+    assert_close(atan2(dec("0"), dec("0")), "0");
+    assert_close(atan2(dec("0"), dec("5")), "0");
+    assert_close(atan2(dec("0"), dec("-5")), &pi.to_string());
+    assert_close(atan2(dec("5"), dec("0")), &half_pi.to_string());
+    assert_close(atan2(dec("-5"), dec("0")), &(-half_pi).to_string());
+    assert_close(
+        atan2(dec("1"), dec("-1")),
+        &pi.checked_sub(quarter_pi).unwrap().to_string(),
+    );
+    assert_close(
+        atan2(dec("-1"), dec("-1")),
+        &quarter_pi.checked_sub(pi).unwrap().to_string(),
+    );
+    assert_close(atan2(dec("-2"), dec("3")), "-0.588002603547567551");
+
+    assert_close(
+        angle_between((dec("1"), dec("0")), (dec("0"), dec("2"))),
+        &half_pi.to_string(),
+    );
+    assert_close(
+        angle_between((dec("0"), dec("2")), (dec("1"), dec("0"))),
+        &(-half_pi).to_string(),
+    );
+    assert_close(
+        angle_between((dec("3"), dec("3")), (dec("-1"), dec("-1"))),
+        &pi.to_string(),
+    );
+
+    // Reserve-sized components whose products are far beyond SignedDecimal::MAX
+    let big = dec("100000000000000000000000000000000000000000");
+    assert_close(
+        angle_between((big, dec("0")), (dec("0"), big)),
+        &half_pi.to_string(),
+    );
+    assert_close(
+        angle_between((big, big), (-big, dec("-3"))),
+        &pi.checked_sub(quarter_pi).unwrap().to_string(),
+    );
+    assert_close(
+        angle_between(
+            (SignedDecimal::MAX, SignedDecimal::MIN),
+            (SignedDecimal::MIN, big),
+        ),
+        &quarter_pi.checked_sub(pi).unwrap().to_string(),
+    );
+    assert_close(angle_between((dec("0"), dec("0")), (big, big)), "0");
+}
+
+#[test]
+fn test_tanh() {
+    assert_close(tanh(dec("0")), "0");
+    assert_close(tanh(dec("0.000001")), "0.000000999999999999");
+    assert_close(tanh(dec("0.5")), "0.462117157260009758");
+    assert_close(tanh(dec("-0.5")), "-0.462117157260009758");
+    assert_close(tanh(dec("1")), "0.761594155955764888");
+    assert_close(tanh(dec("-3.7")), "-0.998778241281131197");
+    assert_close(tanh(dec("10")), "0.999999995877692763");
+    assert_close(tanh(dec("21.9")), "1");
+    assert_eq!(tanh(dec("50")), dec("1"));
+    assert_eq!(tanh(dec("-1000000")), dec("-1"));
+}
@@ -0,0 +1,133 @@
+//! Conversions between `Uint256` or `SignedInt` and the `U256` of
+//! primitive-types and ruint, for indexers built on those stacks. ethers-rs
+//! and alloy use these same types, see the `ethers` and `alloy` features.
+//!
+//! Neither `Uint256` nor `U256` is local to this crate, so those two convert
+//! through the `to_uint256` and `from_uint256` functions of each module.
+
+use cosmwasm_std::{ConversionOverflowError, Uint256};
+
+use crate::{error::CommonResult, signed_int::SignedInt};
+
+/// Magnitude of a non-negative value
+pub(crate) fn non_negative(value: SignedInt) -> CommonResult<Uint256> {
+    if !value.is_positive {
+        return Err(ConversionOverflowError::new("SignedInt", "U256", value.to_string()).into());
+    }
+    Ok(value.value)
+}
+
+/// Implements the conversions for one library given how its `U256` goes to
+/// and from big-endian bytes
+macro_rules! impl_u256_conversions {
+    (
+        $lib:ident,
+        $u256:path,
+        |$u:ident| $to_bytes:expr,
+        |$bytes:ident| $from_bytes:expr
+    ) => {
+        pub mod $lib {
+            use std::convert::TryFrom;
+
+            use cosmwasm_std::Uint256;
+            use $u256 as U256;
+
+            use super::non_negative;
+            use crate::{
+                error::{CommonError, CommonResult},
+                signed_int::SignedInt,
+            };
+
+            pub fn to_uint256($u: U256) -> Uint256 {
+                Uint256::from_be_bytes($to_bytes)
+            }
+
+            pub fn from_uint256(value: Uint256) -> U256 {
+                let $bytes = value.to_be_bytes();
+                $from_bytes
+            }
+
+            impl From<U256> for SignedInt {
+                fn from(value: U256) -> Self {
+                    SignedInt::from(to_uint256(value))
+                }
+            }
+
+            /// Fails for NaN and negative values
+            impl TryFrom<SignedInt> for U256 {
+                type Error = CommonError;
+
+                fn try_from(value: SignedInt) -> CommonResult<Self> {
+                    Ok(from_uint256(non_negative(value)?))
+                }
+            }
+        }
+    };
+}
+
+#[cfg(feature = "primitive-types")]
+impl_u256_conversions!(
+    primitive_types,
+    ::primitive_types::U256,
+    |value| {
+        let mut bytes = [0u8; 32];
+        value.to_big_endian(&mut bytes);
+        bytes
+    },
+    |bytes| U256::from_big_endian(&bytes)
+);
+
+#[cfg(feature = "ruint")]
+impl_u256_conversions!(
+    ruint,
+    ::ruint::aliases::U256,
+    |value| value.to_be_bytes::<32>(),
+    |bytes| U256::from_be_bytes(bytes)
+);
+
+#[test]
+fn test_non_negative() {
+    use std::str::FromStr;
+
+    assert_eq!(non_negative(SignedInt::MAX).unwrap(), Uint256::MAX);
+    assert_eq!(
+        non_negative(SignedInt::from_str("42").unwrap()).unwrap(),
+        Uint256::from_u128(42)
+    );
+    assert!(non_negative(SignedInt::from_str("-1").unwrap()).is_err());
+    assert!(non_negative(SignedInt::nan()).is_err());
+}
+
+#[test]
+#[cfg(feature = "primitive-types")]
+fn test_primitive_types() {
+    use std::convert::TryFrom;
+
+    use ::primitive_types::U256;
+
+    assert_eq!(primitive_types::to_uint256(U256::MAX), Uint256::MAX);
+    assert_eq!(
+        primitive_types::from_uint256(Uint256::from_u128(1 << 100)),
+        U256::from(1u128 << 100)
+    );
+    assert_eq!(SignedInt::from(U256::from(7u8)), SignedInt::from(7u8));
+    assert_eq!(U256::try_from(SignedInt::MAX).unwrap(), U256::MAX);
+    assert!(U256::try_from(SignedInt::from(-7i64)).is_err());
+}
+
+#[test]
+#[cfg(feature = "ruint")]
+fn test_ruint() {
+    use std::convert::TryFrom;
+
+    use ::ruint::aliases::U256;
+
+    assert_eq!(ruint::to_uint256(U256::MAX), Uint256::MAX);
+    assert_eq!(
+        ruint::from_uint256(Uint256::from_u128(1 << 100)),
+        U256::from(1u128 << 100)
+    );
+    assert_eq!(SignedInt::from(U256::from(7u8)), SignedInt::from(7u8));
+    assert_eq!(U256::try_from(SignedInt::MAX).unwrap(), U256::MAX);
+    assert!(U256::try_from(SignedInt::from(-7i64)).is_err());
+}
@@ -0,0 +1,116 @@
+use std::{cmp::Ordering, str::FromStr};
+
+use serde::Serialize;
+use serde_json::Value;
+
+use crate::{
+    error::{CommonError, CommonResult},
+    signed_decimal::SignedDecimal,
+    signed_int::SignedInt,
+};
+
+/// Compact JSON with every object's keys in a fixed order, for hashing query
+/// responses deterministically.
+///
+/// Maps whose keys are all signed numbers are ordered numerically, so "-10"
+/// comes before "-2" and "3", using SignedInt when every key is an integer
+/// and SignedDecimal otherwise. Any other map is ordered by its key strings.
+/// Keys that compare equal, such as "0" and "-0", fall back to string order.
+pub fn canonical_json(value: &impl Serialize) -> CommonResult<String> {
+    let value = serde_json::to_value(value).map_err(|e| CommonError::Generic(e.to_string()))?;
+    let mut out = String::new();
+    write_canonical(&value, &mut out);
+    Ok(out)
+}
+
+fn write_canonical(value: &Value, out: &mut String) {
+    match value {
+        Value::Array(items) => {
+            out.push('[');
+            for (i, item) in items.iter().enumerate() {
+                if i > 0 {
+                    out.push(',');
+                }
+                write_canonical(item, out);
+            }
+            out.push(']');
+        }
+        Value::Object(map) => {
+            let mut keys: Vec<&String> = map.keys().collect();
+            sort_keys(&mut keys);
+            out.push('{');
+            for (i, key) in keys.into_iter().enumerate() {
+                if i > 0 {
+                    out.push(',');
+                }
+                out.push_str(&Value::String(key.clone()).to_string());
+                out.push(':');
+                write_canonical(&map[key], out);
+            }
+            out.push('}');
+        }
+        scalar => out.push_str(&scalar.to_string()),
+    }
+}
+
+fn sort_keys(keys: &mut [&String]) {
+    if let Some(order) = numeric_order::<SignedInt>(keys) {
+        keys.sort_by(|a, b| order(a, b).then_with(|| a.cmp(b)));
+    } else if let Some(order) = numeric_order::<SignedDecimal>(keys) {
+        keys.sort_by(|a, b| order(a, b).then_with(|| a.cmp(b)));
+    } else {
+        keys.sort();
+    }
+}
+
+/// Numeric comparison of the keys as `T`, if every key parses as one
+fn numeric_order<T: FromStr + Ord>(keys: &[&String]) -> Option<impl Fn(&str, &str) -> Ordering> {
+    if !keys.iter().all(|key| T::from_str(key).is_ok()) {
+        return None;
+    }
+    Some(|a: &str, b: &str| match (T::from_str(a), T::from_str(b)) {
+        (Ok(a), Ok(b)) => a.cmp(&b),
+        _ => Ordering::Equal,
+    })
+}
+
+#[test]
+fn test_canonical_json() {
+    use std::collections::{BTreeMap, HashMap};
+
+    let dec = |s: &str| SignedDecimal::from_str(s).unwrap();
+
+    let mut by_tick = HashMap::new();
+    for tick in ["3", "-10", "-2", "0", "25"] {
+        by_tick.insert(tick.to_string(), dec(tick));
+    }
+    assert_eq!(
+        canonical_json(&by_tick).unwrap(),
+        r#"{"-10":"-10","-2":"-2","0":"0.0","3":"3","25":"25"}"#
+    );
+
+    let mut prices = BTreeMap::new();
+    for price in ["1.5", "-0.25", "-1", "10"] {
+        prices.insert(price, vec![price]);
+    }
+    let value = serde_json::json!({ "z": prices, "a": [{ "y": 1, "b": null }] });
+    assert_eq!(
+        canonical_json(&value).unwrap(),
+        r#"{"a":[{"b":null,"y":1}],"z":{"-1":["-1"],"-0.25":["-0.25"],"1.5":["1.5"],"10":["10"]}}"#
+    );
+
+    // Integers too large for a SignedDecimal still sort numerically
+    let big = SignedInt::MIN.to_string();
+    let ints: HashMap<_, _> = [(big.clone(), 0), ("-1".to_string(), 1)].into();
+    assert_eq!(
+        canonical_json(&ints).unwrap(),
+        format!(r#"{{"{big}":0,"-1":1}}"#)
+    );
+
+    // Equal numbers are ordered by their strings, so the output is stable
+    let zeros: HashMap<_, _> = [("0", 1), ("-0", 2), ("0.0", 3)].into();
+    assert_eq!(canonical_json(&zeros).unwrap(), r#"{"-0":2,"0":1,"0.0":3}"#);
+
+    let mixed: HashMap<_, _> = [("b", 1), ("-2", 2), ("10", 3)].into();
+    assert_eq!(canonical_json(&mixed).unwrap(), r#"{"-2":2,"10":3,"b":1}"#);
+}
@@ -0,0 +1,180 @@
+use cosmwasm_std::{Decimal256, Uint256};
+
+use crate::{signed_decimal::SignedDecimal, signed_int::SignedInt};
+
+const ONES: [&str; 20] = [
+    "zero",
+    "one",
+    "two",
+    "three",
+    "four",
+    "five",
+    "six",
+    "seven",
+    "eight",
+    "nine",
+    "ten",
+    "eleven",
+    "twelve",
+    "thirteen",
+    "fourteen",
+    "fifteen",
+    "sixteen",
+    "seventeen",
+    "eighteen",
+    "nineteen",
+];
+
+const TENS: [&str; 10] = [
+    "", "", "twenty", "thirty", "forty", "fifty", "sixty", "seventy", "eighty", "ninety",
+];
+
+/// Short scale names for each group of three digits, enough for Uint256::MAX
+const SCALES: [&str; 26] = [
+    "",
+    "thousand",
+    "million",
+    "billion",
+    "trillion",
+    "quadrillion",
+    "quintillion",
+    "sextillion",
+    "septillion",
+    "octillion",
+    "nonillion",
+    "decillion",
+    "undecillion",
+    "duodecillion",
+    "tredecillion",
+    "quattuordecillion",
+    "quindecillion",
+    "sexdecillion",
+    "septendecillion",
+    "octodecillion",
+    "novemdecillion",
+    "vigintillion",
+    "unvigintillion",
+    "duovigintillion",
+    "trevigintillion",
+    "quattuorvigintillion",
+];
+
+impl SignedInt {
+    /// Spells self out in English, e.g. "negative one hundred twenty-three".
+    ///
+    /// Uses the short scale without "and", so the same value always gives the
+    /// same words. NaN reads as "not a number".
+    pub fn to_words_en(&self) -> String {
+        if self.is_nan() {
+            return String::from("not a number");
+        }
+        with_sign(self.is_positive, integer_words(self.value))
+    }
+}
+
+impl SignedDecimal {
+    /// Spells self out in English, reading the fractional digits one by one,
+    /// e.g. "negative one hundred point zero five". See
+    /// [`SignedInt::to_words_en`].
+    pub fn to_words_en(&self) -> String {
+        let value = self.abs_value();
+        let whole = value.atomics() / Decimal256::one().atomics();
+        let mut words = integer_words(whole);
+        let digits = value.to_string();
+        if let Some((_, fraction)) = digits.split_once('.') {
+            words.push_str(" point");
+            for digit in fraction.bytes() {
+                words.push(' ');
+                words.push_str(ONES[(digit - b'0') as usize]);
+            }
+        }
+        with_sign(self.is_gte_zero(), words)
+    }
+}
+
+fn with_sign(is_positive: bool, words: String) -> String {
+    if is_positive || words == ONES[0] {
+        words
+    } else {
+        format!("negative {}", words)
+    }
+}
+
+fn integer_words(value: Uint256) -> String {
+    let digits = value.to_string();
+    // Split into groups of three digits, most significant first
+    let first_len = match digits.len() % 3 {
+        0 => 3,
+        n => n,
+    };
+    let mut groups = vec![&digits[..first_len]];
+    let mut rest = &digits[first_len..];
+    while !rest.is_empty() {
+        groups.push(&rest[..3]);
+        rest = &rest[3..];
+    }
+
+    let mut parts = vec![];
+    for (i, group) in groups.iter().enumerate() {
+        let n: usize = group.parse().unwrap();
+        if n == 0 {
+            continue;
+        }
+        parts.push(below_thousand(n));
+        let scale = SCALES[groups.len() - 1 - i];
+        if !scale.is_empty() {
+            parts.push(scale.to_string());
+        }
+    }
+    if parts.is_empty() {
+        String::from(ONES[0])
+    } else {
+        parts.join(" ")
+    }
+}
+
+fn below_thousand(n: usize) -> String {
+    let mut parts = vec![];
+    if n >= 100 {
+        parts.push(format!("{} hundred", ONES[n / 100]));
+    }
+    match n % 100 {
+        0 => {}
+        r if r < 20 => parts.push(ONES[r].to_string()),
+        r if r % 10 == 0 => parts.push(TENS[r / 10].to_string()),
+        r => parts.push(format!("{}-{}", TENS[r / 10], ONES[r % 10])),
+    }
+    parts.join(" ")
+}
+
+#[test]
+fn test_to_words_en() {
+    use std::str::FromStr;
+
+    let int = |s: &str| SignedInt::from_str(s).unwrap().to_words_en();
+    let dec = |s: &str| SignedDecimal::from_str(s).unwrap().to_words_en();
+
+    assert_eq!(int("0"), "zero");
+    assert_eq!(int("-0"), "zero");
+    assert_eq!(int("7"), "seven");
+    assert_eq!(int("-15"), "negative fifteen");
+    assert_eq!(int("40"), "forty");
+    assert_eq!(int("123"), "one hundred twenty-three");
+    assert_eq!(int("1000"), "one thousand");
+    assert_eq!(int("-2000017"), "negative two million seventeen");
+    assert_eq!(int("1001001"), "one million one thousand one");
+    assert!(SignedInt::MAX
+        .to_words_en()
+        .starts_with("one hundred fifteen quattuorvigintillion"));
+    let nan = SignedInt {
+        value: Uint256::zero(),
+        is_positive: false,
+    };
+    assert_eq!(nan.to_words_en(), "not a number");
+
+    assert_eq!(dec("0"), "zero");
+    assert_eq!(dec("-100.5"), "negative one hundred point five");
+    assert_eq!(dec("0.05"), "zero point zero five");
+    assert_eq!(dec("-0.000000000000000001"), "negative zero point zero zero zero zero zero zero zero zero zero zero zero zero zero zero zero zero zero one");
+    assert_eq!(dec("21.10"), "twenty-one point one");
+}